@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc::answers::Answers;
+use aoc::{Day, Failure};
+
+/// Benches parsing and each implemented part of every day with an input, as `<year>/dayNN/<step>`. A
+/// subset can be selected by passing a filter to criterion, e.g. `cargo bench -- 2020/day15`.
+fn all_days(c: &mut Criterion) {
+    for year in aoc_cli::YEARS {
+        let answers = Answers::load(&aoc::year_dir(year.year).join("answers.toml")).unwrap();
+
+        for day in year.days {
+            bench_day(c, year.year, day, &answers);
+        }
+    }
+}
+
+fn bench_day(c: &mut Criterion, year: u16, day: &Day, answers: &Answers) {
+    let input = match day.input {
+        Some(input) => input,
+        None => return,
+    };
+
+    // each part is solved once to find out which ones exist, missing parts are left out by
+    // `solve_timed` and `todo!()` ones would only measure a panic
+    let solved = day
+        .solve_timed(input, None)
+        .map_err(Failure::into_error)
+        .unwrap();
+    let parts: Vec<u8> = solved
+        .parts
+        .iter()
+        .filter(|p| !matches!(p.answer, Err(Failure::Todo)))
+        .map(|p| p.part)
+        .collect();
+
+    let mut group = c.benchmark_group(format!("{}/day{:02}", year, day.day));
+    if parts.iter().any(|&part| answers.is_slow(day.day, part)) {
+        group.sample_size(10);
+    }
+
+    group.bench_function("parse", |b| b.iter(|| day.solution.parse(input).unwrap()));
+
+    let parsed = day.solution.parse(input).unwrap();
+    for part in parts {
+        group.bench_function(format!("part{}", part), |b| match part {
+            1 => b.iter(|| day.solution.part1(parsed.as_ref()).unwrap()),
+            _ => b.iter(|| day.solution.part2(parsed.as_ref()).unwrap()),
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, all_days);
+criterion_main!(benches);
@@ -0,0 +1,13 @@
+use aoc::Year;
+
+/// Every year with solutions, shared by the `aoc` binary and the benches.
+pub const YEARS: &[Year] = &[
+    aoc2015::YEAR,
+    aoc2018::YEAR,
+    aoc2019::YEAR,
+    aoc2020::YEAR,
+    aoc2021::YEAR,
+    aoc2022::YEAR,
+    aoc2023::YEAR,
+    aoc2025::YEAR,
+];
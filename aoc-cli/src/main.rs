@@ -0,0 +1,8 @@
+use anyhow::Result;
+
+#[global_allocator]
+static ALLOC: aoc::mem::Counting = aoc::mem::Counting;
+
+fn main() -> Result<()> {
+    aoc::run(aoc_cli::YEARS)
+}
@@ -0,0 +1,41 @@
+//! Runs the `aoc` binary, checking that the memory `--mem` reports for a day doesn't depend on the
+//! other days of the run.
+
+use std::process::Command;
+
+/// Runs `aoc 2018 <days> --mem`, returning its CSV records without the duration column.
+fn mem_records(days: &str) -> Vec<Vec<String>> {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["2018", days, "--mem", "--format", "csv"])
+        .output()
+        .expect("couldn't run aoc");
+    assert!(
+        output.status.success(),
+        "aoc 2018 {} --mem failed: {}",
+        days,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .skip(1)
+        .map(|line| {
+            let mut fields: Vec<_> = line.split(',').map(str::to_string).collect();
+            fields.remove(5);
+            fields
+        })
+        .collect()
+}
+
+#[test]
+fn same_usage_as_single_days() {
+    if aoc2018::YEAR.days[..2].iter().any(|d| d.input.is_none()) {
+        eprintln!("skipping: the inputs of 2018 days 1 and 2 weren't embedded");
+        return;
+    }
+
+    let single_days: Vec<_> = ["1", "2"].into_iter().flat_map(mem_records).collect();
+
+    assert_eq!(mem_records("1-2"), single_days);
+}
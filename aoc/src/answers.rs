@@ -0,0 +1,245 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::{Answer, Day, Year};
+
+/// Known answers to a year's puzzles, stored in `aocYYYY/answers.toml`:
+///
+/// ```toml
+/// [day04]
+/// part1 = 282749
+/// part2 = 9962624
+/// # parts which take too long to be checked by a plain `cargo test`
+/// slow = [1, 2]
+///
+/// # answers to the example of the puzzle's description, `input/day04_provided.txt`
+/// [day04.provided]
+/// part1 = 609043
+/// ```
+///
+/// Drawings are stored as multi-line strings.
+#[derive(Default)]
+pub struct Answers {
+    table: toml::Table,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self> {
+        let table = s.parse().context("couldn't parse answers")?;
+
+        Ok(Answers { table })
+    }
+
+    /// Loads the answers stored at `path`. A missing file simply means no answer is known yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                Self::parse(&content).with_context(|| format!("in `{}'", path.display()))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("couldn't read `{}'", path.display())),
+        }
+    }
+
+    /// Returns the known answer to `part` of `day`, if any.
+    pub fn get(&self, day: u8, part: u8) -> Result<Option<String>> {
+        part_answer(self.day(day), day, part)
+    }
+
+    /// Returns the known answer to `part` of `day` on its provided example, if any.
+    pub fn get_provided(&self, day: u8, part: u8) -> Result<Option<String>> {
+        let provided = self
+            .day(day)
+            .and_then(|d| d.get("provided"))
+            .and_then(toml::Value::as_table);
+
+        part_answer(provided, day, part)
+    }
+
+    /// Whether `part` of `day` is too slow to be checked by default in tests.
+    pub fn is_slow(&self, day: u8, part: u8) -> bool {
+        self.day(day)
+            .and_then(|d| d.get("slow"))
+            .and_then(toml::Value::as_array)
+            .is_some_and(|slow| slow.iter().any(|p| p.as_integer() == Some(part.into())))
+    }
+
+    /// All the days with at least one known answer, in order.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .table
+            .keys()
+            .filter_map(|key| key.strip_prefix("day")?.parse().ok())
+            .collect();
+        days.sort_unstable();
+
+        days
+    }
+
+    fn day(&self, day: u8) -> Option<&toml::Table> {
+        self.table
+            .get(&format!("day{:02}", day))
+            .and_then(toml::Value::as_table)
+    }
+}
+
+fn part_answer(table: Option<&toml::Table>, day: u8, part: u8) -> Result<Option<String>> {
+    let value = match table.and_then(|t| t.get(&format!("part{}", part))) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    match value {
+        toml::Value::Integer(n) => Ok(Some(n.to_string())),
+        toml::Value::String(s) => Ok(Some(s.clone())),
+        _ => bail!(
+            "answer to day {} part {} should be an integer or a string",
+            day,
+            part
+        ),
+    }
+}
+
+/// Records `answer` as the known answer to `part` of `day`, in the answers file at `path`. The
+/// rest of the file is left as is, comments included.
+pub fn record(path: &Path, day: u8, part: u8, answer: &str) -> Result<()> {
+    edit(path, |doc| {
+        let day = day_table(doc, day)?;
+        day[&format!("part{}", part)] = answer_value(answer);
+
+        Ok(())
+    })
+}
+
+/// Same as [`record`], for the answer on the provided example.
+pub fn record_provided(path: &Path, day: u8, part: u8, answer: &str) -> Result<()> {
+    edit(path, |doc| {
+        let provided = day_table(doc, day)?
+            .entry("provided")
+            .or_insert_with(toml_edit::table)
+            .as_table_mut()
+            .context("`provided' should be a table")?;
+        provided[&format!("part{}", part)] = answer_value(answer);
+
+        Ok(())
+    })
+}
+
+fn edit(path: &Path, f: impl FnOnce(&mut toml_edit::DocumentMut) -> Result<()>) -> Result<()> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err).with_context(|| format!("couldn't read `{}'", path.display())),
+    };
+    let mut doc: toml_edit::DocumentMut = content
+        .parse()
+        .with_context(|| format!("couldn't parse `{}'", path.display()))?;
+
+    f(&mut doc).with_context(|| format!("in `{}'", path.display()))?;
+
+    fs::write(path, doc.to_string()).with_context(|| format!("couldn't write `{}'", path.display()))
+}
+
+fn day_table(doc: &mut toml_edit::DocumentMut, day: u8) -> Result<&mut toml_edit::Table> {
+    doc.entry(&format!("day{:02}", day))
+        .or_insert_with(|| {
+            // may only hold the `provided` table, don't print an empty `[dayNN]` header then
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            toml_edit::Item::Table(table)
+        })
+        .as_table_mut()
+        .with_context(|| format!("day{:02} should be a table", day))
+}
+
+fn answer_value(answer: &str) -> toml_edit::Item {
+    match answer.parse::<i64>() {
+        Ok(n) => toml_edit::value(n),
+        Err(_) => toml_edit::value(answer),
+    }
+}
+
+/// Whether `answer` matches the `expected` one, ignoring trailing whitespace on each line (which
+/// matters for drawings).
+pub fn matches(answer: &Answer, expected: &str) -> bool {
+    normalize(&answer.to_string()) == normalize(expected)
+}
+
+fn normalize(s: &str) -> String {
+    s.lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
+}
+
+/// Solves `part` of `day` on its embedded input, and panics if the answer isn't `expected`. Days
+/// without an input are skipped.
+///
+/// This backs the tests generated from each year's `answers.toml`.
+pub fn check(year: &Year, day: u8, part: u8, expected: &str) {
+    let solution = year
+        .day(day)
+        .unwrap_or_else(|| panic!("day {} of {} isn't solved", day, year.year));
+    let input = match solution.input {
+        Some(input) => input,
+        None => {
+            println!(
+                "skipping day {} part {}: input unavailable, input/day{:02}.txt wasn't embedded",
+                day, part, day
+            );
+            return;
+        }
+    };
+
+    let answer = solve_part(solution, input, part).unwrap();
+
+    assert!(
+        matches(&answer, expected),
+        "day {} part {}: expected `{}', got `{}'",
+        day,
+        part,
+        expected,
+        answer
+    );
+}
+
+fn solve_part(day: &Day, input: &str, part: u8) -> Result<Answer> {
+    day.solve(input, Some(part))?
+        .pop()
+        .map(|(_, answer)| answer)
+        .with_context(|| format!("part {} isn't implemented", part))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn record_answers() {
+        let path = env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        fs::write(&path, "# comment\n[day01]\npart1 = 1502\n").unwrap();
+
+        record_provided(&path, 1, 1, "7").unwrap();
+        record_provided(&path, 2, 2, "FOO").unwrap();
+        record(&path, 1, 2, "1538").unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# comment\n[day01]\npart1 = 1502\npart2 = 1538\n"));
+        assert!(!content.contains("[day02]\n"));
+
+        let answers = Answers::parse(&content).unwrap();
+        assert_eq!(answers.get(1, 1).unwrap().as_deref(), Some("1502"));
+        assert_eq!(answers.get_provided(1, 1).unwrap().as_deref(), Some("7"));
+        assert_eq!(answers.get_provided(2, 2).unwrap().as_deref(), Some("FOO"));
+        assert_eq!(answers.get(2, 2).unwrap(), None);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::{year_dir, Failure, Solved};
+
+/// Differences smaller than this are noise, whatever the threshold.
+const NOISE: Duration = Duration::from_millis(1);
+
+/// What to do with the timings of a run.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Mode {
+    /// Store them as the new baseline of the days which ran.
+    Save,
+    /// Report the steps slower than their baseline.
+    Compare,
+    /// Same as `Compare`, but regressions make the run fail.
+    Check,
+}
+
+impl FromStr for Mode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "save" => Ok(Mode::Save),
+            "compare" => Ok(Mode::Compare),
+            "check" => Ok(Mode::Check),
+            _ => bail!(
+                "baseline mode must be one of save, compare or check, got `{}'",
+                s
+            ),
+        }
+    }
+}
+
+/// The steps of a day: parsing, then each part.
+type Steps = [Option<Duration>; 3];
+
+const STEPS: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Timings of a year's days, stored in `aocYYYY/timings.toml` in microseconds:
+///
+/// ```toml
+/// [day15]
+/// parse = 12
+/// part1 = 85
+/// part2 = 1204133
+/// ```
+pub(crate) struct Baseline {
+    mode: Mode,
+    /// Percentage by which a step may be slower than its baseline.
+    threshold: f64,
+    path: PathBuf,
+    stored: BTreeMap<u8, Steps>,
+    measured: BTreeMap<u8, Steps>,
+}
+
+impl Baseline {
+    pub(crate) fn load(year: u16, mode: Mode, threshold: f64) -> Result<Self> {
+        let path = year_dir(year).join("timings.toml");
+        let stored = match fs::read_to_string(&path) {
+            Ok(content) => parse(&content).with_context(|| format!("in `{}'", path.display()))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => {
+                return Err(err).with_context(|| format!("couldn't read `{}'", path.display()))
+            }
+        };
+
+        Ok(Baseline {
+            mode,
+            threshold,
+            path,
+            stored,
+            measured: BTreeMap::new(),
+        })
+    }
+
+    /// Adds the timings of `day`, only counting the steps which succeeded.
+    pub(crate) fn add(&mut self, day: u8, solved: &Result<Solved, Failure>) {
+        let solved = match solved {
+            Ok(solved) => solved,
+            Err(_) => return,
+        };
+
+        let mut steps = [Some(solved.parse), None, None];
+        for p in solved.parts.iter().filter(|p| p.answer.is_ok()) {
+            steps[usize::from(p.part)] = Some(p.duration);
+        }
+        self.measured.insert(day, steps);
+    }
+
+    /// Saves the baseline, or compares the run against it depending on the mode. Fails in check
+    /// mode if a step regressed.
+    pub(crate) fn finish(mut self) -> Result<()> {
+        if self.mode == Mode::Save {
+            let days = self.measured.len();
+            merge(&mut self.stored, self.measured);
+            fs::write(&self.path, serialize(&self.stored))
+                .with_context(|| format!("couldn't write `{}'", self.path.display()))?;
+            eprintln!(
+                "saved timings of {} days to `{}'",
+                days,
+                self.path.display()
+            );
+
+            return Ok(());
+        }
+
+        let regressions = regressions(&self.stored, &self.measured, self.threshold);
+        for regression in &regressions {
+            eprintln!("{}", regression);
+        }
+        eprintln!(
+            "{} step(s) slower than `{}' by more than {}%",
+            regressions.len(),
+            self.path.display(),
+            self.threshold
+        );
+
+        if self.mode == Mode::Check && !regressions.is_empty() {
+            bail!("{} performance regression(s)", regressions.len());
+        }
+
+        Ok(())
+    }
+}
+
+/// Overwrites the steps of `stored` which were `measured`, keeping the others: running a single
+/// part, or having one fail, doesn't lose the baseline of the rest of the day.
+fn merge(stored: &mut BTreeMap<u8, Steps>, measured: BTreeMap<u8, Steps>) {
+    for (day, steps) in measured {
+        let baseline = stored.entry(day).or_default();
+        for (baseline, step) in baseline.iter_mut().zip(steps) {
+            if step.is_some() {
+                *baseline = step;
+            }
+        }
+    }
+}
+
+fn parse(content: &str) -> Result<BTreeMap<u8, Steps>> {
+    let table: toml::Table = content.parse().context("couldn't parse timings")?;
+    let mut days = BTreeMap::new();
+
+    for (key, value) in &table {
+        let day = key
+            .strip_prefix("day")
+            .and_then(|d| d.parse().ok())
+            .with_context(|| format!("unexpected table `{}'", key))?;
+
+        let mut steps = [None; 3];
+        for (step, name) in steps.iter_mut().zip(STEPS) {
+            *step = match value.get(name) {
+                Some(toml::Value::Integer(micros)) if *micros >= 0 => {
+                    Some(Duration::from_micros(*micros as u64))
+                }
+                Some(_) => bail!("{} of {} should be a number of microseconds", name, key),
+                None => None,
+            };
+        }
+        days.insert(day, steps);
+    }
+
+    Ok(days)
+}
+
+fn serialize(days: &BTreeMap<u8, Steps>) -> String {
+    let mut out = String::from("# written by `aoc <year> --baseline save', in microseconds\n");
+
+    for (day, steps) in days {
+        write!(out, "\n[day{:02}]\n", day).unwrap();
+        for (step, name) in steps.iter().zip(STEPS) {
+            if let Some(duration) = step {
+                writeln!(out, "{} = {}", name, duration.as_micros()).unwrap();
+            }
+        }
+    }
+
+    out
+}
+
+/// Describes the steps of `measured` slower than in `stored` by more than `threshold` percent.
+fn regressions(
+    stored: &BTreeMap<u8, Steps>,
+    measured: &BTreeMap<u8, Steps>,
+    threshold: f64,
+) -> Vec<String> {
+    let mut regressions = Vec::new();
+
+    for (day, steps) in measured {
+        let baseline = match stored.get(day) {
+            Some(baseline) => baseline,
+            None => continue,
+        };
+
+        for ((step, baseline), name) in steps.iter().zip(baseline).zip(STEPS) {
+            if let (Some(step), Some(baseline)) = (step, baseline) {
+                let limit = baseline.mul_f64(1.0 + threshold / 100.0);
+                if *step > limit && *step - *baseline > NOISE {
+                    regressions.push(format!(
+                        "day {} {}: {:.2?}, was {:.2?} (+{:.0}%)",
+                        day,
+                        name,
+                        step,
+                        baseline,
+                        (step.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+                    ));
+                }
+            }
+        }
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Option<Duration> {
+        Some(Duration::from_millis(ms))
+    }
+
+    #[test]
+    fn round_trip() {
+        let days = BTreeMap::from([(1, [ms(1), ms(20), None]), (15, [ms(3), ms(4), ms(5)])]);
+        let content = serialize(&days);
+
+        assert!(content.contains("[day01]\nparse = 1000\npart1 = 20000\n\n[day15]"));
+        assert_eq!(parse(&content).unwrap(), days);
+    }
+
+    #[test]
+    fn merges_steps() {
+        let mut stored = BTreeMap::from([(1, [ms(1), ms(2), ms(3)]), (2, [ms(1), ms(1), ms(1)])]);
+        let measured = BTreeMap::from([
+            // only part 1 ran
+            (1, [ms(4), ms(5), None]),
+            (3, [ms(6), None, ms(7)]),
+        ]);
+
+        merge(&mut stored, measured);
+        assert_eq!(
+            stored,
+            BTreeMap::from([
+                (1, [ms(4), ms(5), ms(3)]),
+                (2, [ms(1), ms(1), ms(1)]),
+                (3, [ms(6), None, ms(7)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let stored = BTreeMap::from([(1, [ms(1), ms(100), ms(100)]), (2, [ms(1), ms(1), None])]);
+        let measured = BTreeMap::from([
+            // parse within the noise, part 1 within the threshold, part 2 regressed
+            (1, [ms(2), ms(115), ms(130)]),
+            (2, [ms(1), ms(1), ms(50)]),
+            // no baseline yet
+            (3, [ms(1), ms(1000), None]),
+        ]);
+
+        let regressions = regressions(&stored, &measured, 20.0);
+        assert_eq!(
+            regressions,
+            vec!["day 1 part2: 130.00ms, was 100.00ms (+30%)"]
+        );
+    }
+}
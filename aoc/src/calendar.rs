@@ -0,0 +1,134 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Result};
+
+/// Puzzles unlock at midnight EST, which is UTC-5.
+const UNLOCK_OFFSET: i64 = 5 * 3600;
+
+const SECS_PER_DAY: i64 = 24 * 3600;
+
+/// Number of puzzles in a given year's event.
+pub(crate) fn puzzles_in(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Checks that `day` of `year` is a puzzle that exists and is unlocked at `now`, a Unix
+/// timestamp.
+pub(crate) fn check_unlocked(year: u16, day: u8, now: i64) -> Result<()> {
+    if year < 2015 {
+        bail!(
+            "the first event was in 2015, there's no puzzle for {}",
+            year
+        );
+    }
+    if day == 0 || day > puzzles_in(year) {
+        bail!(
+            "the {} event has puzzles for days 1 to {}, not {}",
+            year,
+            puzzles_in(year),
+            day
+        );
+    }
+
+    let unlock = days_from_civil(year.into(), 12, day.into()) * SECS_PER_DAY + UNLOCK_OFFSET;
+    if now < unlock {
+        let wait = unlock - now;
+        bail!(
+            "day {} of {} isn't unlocked yet, wait {}h{:02}m{:02}s",
+            day,
+            year,
+            wait / 3600,
+            wait % 3600 / 60,
+            wait % 60
+        );
+    }
+
+    Ok(())
+}
+
+/// The latest unlocked puzzle at `now`, if an event is running.
+pub(crate) fn current_puzzle(now: i64) -> Option<(u16, u8)> {
+    let (year, month, day) = civil_from_days((now - UNLOCK_OFFSET).div_euclid(SECS_PER_DAY));
+    let year = u16::try_from(year).ok()?;
+    let day = u8::try_from(day).ok()?;
+
+    (month == 12 && day <= puzzles_in(year)).then_some((year, day))
+}
+
+pub(crate) fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+// The two functions below are adapted from Howard Hinnant's date algorithms:
+// http://howardhinnant.github.io/date_algorithms.html
+
+/// Number of days since 1970-01-01 of the given date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+/// Date of the day `days` after 1970-01-01, as `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2021-12-01T05:00:00Z, when 2021's first puzzle unlocked
+    const UNLOCK_2021_01: i64 = 1638334800;
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2021, 12, 1), UNLOCK_2021_01 / SECS_PER_DAY);
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(
+            civil_from_days(UNLOCK_2021_01 / SECS_PER_DAY),
+            (2021, 12, 1)
+        );
+    }
+
+    #[test]
+    fn unlocked() {
+        assert!(check_unlocked(2021, 1, UNLOCK_2021_01).is_ok());
+        assert!(check_unlocked(2021, 1, UNLOCK_2021_01 - 1).is_err());
+        assert!(check_unlocked(2021, 2, UNLOCK_2021_01).is_err());
+        assert!(check_unlocked(2021, 26, UNLOCK_2021_01 + 365 * SECS_PER_DAY).is_err());
+        assert!(check_unlocked(2025, 13, UNLOCK_2021_01 + 4 * 365 * SECS_PER_DAY).is_err());
+    }
+
+    #[test]
+    fn current() {
+        assert_eq!(current_puzzle(UNLOCK_2021_01), Some((2021, 1)));
+        assert_eq!(current_puzzle(UNLOCK_2021_01 - 1), None);
+        assert_eq!(
+            current_puzzle(UNLOCK_2021_01 + 24 * SECS_PER_DAY),
+            Some((2021, 25))
+        );
+        assert_eq!(current_puzzle(UNLOCK_2021_01 + 25 * SECS_PER_DAY), None);
+    }
+}
@@ -0,0 +1,504 @@
+//! The `aoc` command line tool, which runs the solutions of the year crates and helps writing
+//! new ones.
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+
+use crate::baseline::{self, Baseline};
+use crate::config::Config;
+use crate::output::{Format, Output};
+use crate::{
+    calendar, examples, failure, fetch, mem, pool, scaffold, submit, verify, watch, Day, Failure,
+    Solved, Year,
+};
+
+const USAGE: &str = "\
+usage: aoc list
+       aoc verify [year] [days]
+       aoc fetch [<year> <day>] [--force]
+       aoc new <year> <day>
+       aoc submit <year> <day> <1|2> [--input <path>|-]
+       aoc examples <year> <day> <page.html> [--pick <n>] [--force]
+       aoc watch <year> <day>
+       aoc <year> [days] [--part <1|2>] [--input <path>|-] [--time] [--mem]
+           [--timeout <secs>] [--jobs <n>]
+           [--format <text|json|csv>]
+           [--baseline <save|compare|check>] [--threshold <percent>]";
+
+/// Percentage by which a part may be slower than its baseline, unless given with `--threshold`.
+const DEFAULT_THRESHOLD: f64 = 20.0;
+
+/// Root of the workspace containing this crate and the year crates.
+pub(crate) fn workspace_root() -> &'static Path {
+    // this crate lives at the root of the workspace, next to the year crates
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate should be in a workspace")
+}
+
+/// Directory of the crate holding `year`'s solutions and inputs.
+pub fn year_dir(year: u16) -> PathBuf {
+    workspace_root().join(format!("aoc{}", year))
+}
+
+pub fn run(years: &[Year]) -> Result<()> {
+    let mut args = env::args();
+    args.next();
+
+    let mut positional = Vec::new();
+    let mut input_path = None;
+    let mut force = false;
+    let mut pick = None;
+    let mut opts = Options::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args.next().context("--part requires a part number")?;
+                opts.part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => bail!("part must be 1 or 2, got `{}'", value),
+                };
+            }
+            "--input" => {
+                input_path = Some(args.next().context("--input requires a path, or `-'")?);
+            }
+            "-" => input_path = Some(arg),
+            "--time" => opts.time = true,
+            "--mem" => opts.mem = true,
+            "--force" => force = true,
+            "--pick" => {
+                let value = args.next().context("--pick requires an example number")?;
+                pick = Some(
+                    value
+                        .parse()
+                        .with_context(|| format!("couldn't parse example number `{}'", value))?,
+                );
+            }
+            "--timeout" => {
+                let value = args
+                    .next()
+                    .context("--timeout requires a number of seconds")?;
+                let secs: f64 = value
+                    .parse()
+                    .with_context(|| format!("couldn't parse timeout `{}'", value))?;
+                opts.timeout = Some(
+                    Duration::try_from_secs_f64(secs)
+                        .with_context(|| format!("invalid timeout `{}'", value))?,
+                );
+            }
+            "--jobs" | "-j" => {
+                let value = args.next().context("--jobs requires a number of threads")?;
+                opts.jobs = match value.parse() {
+                    Ok(0) | Err(_) => bail!("number of jobs must be at least 1, got `{}'", value),
+                    Ok(jobs) => Some(jobs),
+                };
+            }
+            "--format" => {
+                let value = args
+                    .next()
+                    .context("--format requires one of text, json or csv")?;
+                opts.format = value.parse()?;
+            }
+            "--baseline" => {
+                let value = args
+                    .next()
+                    .context("--baseline requires one of save, compare or check")?;
+                opts.baseline = Some(value.parse()?);
+            }
+            "--threshold" => {
+                let value = args.next().context("--threshold requires a percentage")?;
+                opts.threshold = match value.parse() {
+                    Ok(threshold) if threshold >= 0.0 => Some(threshold),
+                    _ => bail!("threshold must be a positive percentage, got `{}'", value),
+                };
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    failure::quiet_panics();
+
+    let mut positional = positional.into_iter();
+
+    let year = match positional.next() {
+        Some(arg) if arg == "list" => {
+            list(years);
+            return Ok(());
+        }
+        Some(arg) if arg == "verify" => {
+            let selected = match positional.next() {
+                Some(arg) => {
+                    let year = find_year(years, &arg)?;
+                    let days = match positional.next() {
+                        Some(spec) => year.select(&spec)?,
+                        None => year.days.iter().collect(),
+                    };
+                    vec![(year, days)]
+                }
+                None => years.iter().map(|y| (y, y.days.iter().collect())).collect(),
+            };
+            return verify::verify(&selected);
+        }
+        Some(arg) if arg == "fetch" => {
+            let (year, day) = match (positional.next(), positional.next()) {
+                (Some(year), Some(day)) => (
+                    year.parse().context("couldn't parse year")?,
+                    day.parse().context("couldn't parse day")?,
+                ),
+                (None, _) => calendar::current_puzzle(calendar::now())
+                    .context("no event is running, provide a year and day to fetch")?,
+                (Some(_), None) => bail!("{}", USAGE),
+            };
+            return fetch::fetch(&Config::load()?, year, day, force);
+        }
+        Some(arg) if arg == "examples" => {
+            let (year, day, page) = match (positional.next(), positional.next(), positional.next())
+            {
+                (Some(year), Some(day), Some(page)) => (year, day, page),
+                _ => bail!("{}", USAGE),
+            };
+            return examples::examples(
+                year.parse().context("couldn't parse year")?,
+                day.parse().context("couldn't parse day")?,
+                Path::new(&page),
+                pick,
+                force,
+            );
+        }
+        Some(arg) if arg == "submit" => {
+            let (year, day, part) = match (positional.next(), positional.next(), positional.next())
+            {
+                (Some(year), Some(day), Some(part)) => (year, day, part),
+                _ => bail!("{}", USAGE),
+            };
+            let year = find_year(years, &year)?;
+            let day = match year.select(&day)?[..] {
+                [day] => day,
+                _ => bail!("answers can only be submitted for a single day"),
+            };
+            let part = match part.as_str() {
+                "1" => 1,
+                "2" => 2,
+                _ => bail!("part must be 1 or 2, got `{}'", part),
+            };
+            let input = match input_path {
+                Some(path) => read_input(&path)?,
+                None => embedded_input(day)?.to_string(),
+            };
+
+            return submit::submit(&Config::load()?, year.year, day, part, &input);
+        }
+        Some(arg) if arg == "watch" => {
+            let (year, day) = match (positional.next(), positional.next()) {
+                (Some(year), Some(day)) => (year, day),
+                _ => bail!("{}", USAGE),
+            };
+            let year = find_year(years, &year)?;
+            let day = match year.select(&day)?[..] {
+                [day] => day,
+                _ => bail!("only a single day can be watched"),
+            };
+
+            return watch::watch(year.year, day);
+        }
+        Some(arg) if arg == "new" => {
+            let (year, day) = match (positional.next(), positional.next()) {
+                (Some(year), Some(day)) => (year, day),
+                _ => bail!("{}", USAGE),
+            };
+            return scaffold::new_day(
+                workspace_root(),
+                year.parse().context("couldn't parse year")?,
+                day.parse().context("couldn't parse day")?,
+            );
+        }
+        Some(arg) => find_year(years, &arg)?,
+        None => bail!("{}", USAGE),
+    };
+
+    let days = match positional.next() {
+        Some(spec) => year.select(&spec)?,
+        None => year.days.iter().collect(),
+    };
+
+    if let Some(extra) = positional.next() {
+        bail!("unexpected argument `{}'\n{}", extra, USAGE);
+    }
+
+    let parts = match opts.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    if opts.mem {
+        if opts.jobs.is_some_and(|jobs| jobs > 1) {
+            bail!("memory can only be measured when running one day at a time");
+        }
+        // a day which timed out keeps running, its allocations would count for the next ones
+        if opts.timeout.is_some() {
+            bail!("memory can't be measured along with a timeout");
+        }
+        mem::enable()?;
+    }
+
+    if let Some(mode) = opts.baseline {
+        // days running at the same time slow each other down
+        if opts.jobs.is_some_and(|jobs| jobs > 1) {
+            bail!("baselines can only be used when running one day at a time");
+        }
+        if cfg!(debug_assertions) {
+            if mode == baseline::Mode::Compare {
+                eprintln!("warning: comparing the timings of a debug build, run with `--release'");
+            } else {
+                bail!("baselines can only be saved or checked with a release build, run with `--release'");
+            }
+        }
+    }
+
+    let mut output = Output::new(opts.format, opts.time, opts.mem, year.year);
+    let mut baseline = match opts.baseline {
+        Some(mode) => Some(Baseline::load(
+            year.year,
+            mode,
+            opts.threshold.unwrap_or(DEFAULT_THRESHOLD),
+        )?),
+        None => None,
+    };
+
+    match days[..] {
+        [day] => {
+            let input = match input_path {
+                Some(_) if baseline.is_some() => {
+                    bail!("baselines are only about the embedded inputs, not `--input'")
+                }
+                Some(path) => read_input(&path)?,
+                None => embedded_input(day)?.to_string(),
+            };
+
+            let solved = solve_day(day, &input, &opts);
+            output.day(day, &solved, &parts);
+            if let Some(baseline) = &mut baseline {
+                baseline.add(day.day, &solved);
+            }
+        }
+        _ => {
+            if input_path.is_some() {
+                bail!("an input can only be provided when running a single day");
+            }
+
+            let start = Instant::now();
+
+            let solve = |day: &'static Day| {
+                embedded_input(day)
+                    .map_err(Failure::Error)
+                    .and_then(|input| solve_day(day, input, &opts))
+            };
+            let mut report = |day: &Day, solved: Result<Solved, Failure>| {
+                if output.is_text() {
+                    println!("day{}: ", day.day);
+                }
+                output.day(day, &solved, &parts);
+                if let Some(baseline) = &mut baseline {
+                    baseline.add(day.day, &solved);
+                }
+                if output.is_text() {
+                    println!();
+                }
+            };
+
+            if opts.mem {
+                // allocations are counted for the whole process, a day solved on a worker thread
+                // would also be charged for the reporting of the previous one
+                for &day in &days {
+                    report(day, solve(day));
+                }
+            } else {
+                pool::run_ordered(
+                    &days,
+                    opts.jobs.unwrap_or(1),
+                    |&day| solve(day),
+                    |&day, solved| report(day, solved),
+                );
+            }
+
+            if opts.time || opts.jobs.is_some() {
+                let total = format!("ran {} days in {:.2?}", days.len(), start.elapsed());
+                // keep stdout parseable in the machine readable formats
+                if output.is_text() {
+                    println!("{}", total);
+                } else {
+                    eprintln!("{}", total);
+                }
+            }
+        }
+    }
+
+    let failures = output.finish();
+    if let Some(baseline) = baseline {
+        baseline.finish()?;
+    }
+    if failures > 0 {
+        bail!("{} failure(s) during the run", failures);
+    }
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct Options {
+    part: Option<u8>,
+    time: bool,
+    mem: bool,
+    timeout: Option<Duration>,
+    jobs: Option<usize>,
+    format: Format,
+    baseline: Option<baseline::Mode>,
+    threshold: Option<f64>,
+}
+
+fn solve_day(day: &'static Day, input: &str, opts: &Options) -> Result<Solved, Failure> {
+    match opts.timeout {
+        Some(timeout) => solve_with_timeout(day, input, opts.part, timeout),
+        None => day.solve_timed(input, opts.part),
+    }
+}
+
+/// Solves `day` on a worker thread, giving up on it after `timeout`.
+///
+/// There's no way to stop the worker, so it keeps running in the background until it finishes or
+/// the process exits.
+fn solve_with_timeout(
+    day: &'static Day,
+    input: &str,
+    part: Option<u8>,
+    timeout: Duration,
+) -> Result<Solved, Failure> {
+    let (tx, rx) = mpsc::channel();
+    let input = input.to_string();
+
+    thread::spawn(move || {
+        // the receiver is gone if we timed out, nobody cares about the result anymore
+        let _ = tx.send(day.solve_timed(&input, part));
+    });
+
+    rx.recv_timeout(timeout)
+        .unwrap_or(Err(Failure::Timeout(timeout)))
+}
+
+fn find_year<'a>(years: &'a [Year], arg: &str) -> Result<&'a Year> {
+    let year: u16 = arg.parse().context("couldn't parse year")?;
+
+    years.iter().find(|y| y.year == year).with_context(|| {
+        format!(
+            "no solutions for year {}, run `aoc list' to see which exist",
+            year
+        )
+    })
+}
+
+fn list(years: &[Year]) {
+    for year in years {
+        let days = year
+            .days
+            .iter()
+            .map(|d| d.day.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        println!("{}: {}", year.year, days);
+    }
+}
+
+/// Reads a puzzle input from the file at `path`, or from stdin if `path` is `-`.
+fn read_input(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("couldn't read input from stdin")?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("couldn't read input file `{}'", path))
+    }
+}
+
+fn embedded_input(day: &Day) -> Result<&'static str> {
+    day.input.with_context(|| {
+        format!(
+            "input unavailable for day {}, provide one with `--input <path>'",
+            day.day
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, Solution};
+
+    struct Slow;
+
+    impl Solution for Slow {
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Result<Answer> {
+            thread::sleep(Duration::from_secs(10));
+            Ok(0.into())
+        }
+    }
+
+    struct Fast;
+
+    impl Solution for Fast {
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Result<Answer> {
+            Ok(1.into())
+        }
+    }
+
+    static SLOW: Day = Day {
+        day: 1,
+        solution: &Slow,
+        input: None,
+    };
+
+    static FAST: Day = Day {
+        day: 2,
+        solution: &Fast,
+        input: None,
+    };
+
+    #[test]
+    fn timeout() {
+        let timeout = Duration::from_millis(100);
+
+        let start = Instant::now();
+        let solved = solve_with_timeout(&SLOW, "", None, timeout);
+        assert!(matches!(solved, Err(Failure::Timeout(t)) if t == timeout));
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        // the slow day is still running in the background, that doesn't prevent the next one
+        let solved = solve_with_timeout(&FAST, "", None, timeout).ok().unwrap();
+        assert!(matches!(solved.parts[0].answer, Ok(Answer::Int(1))));
+    }
+}
@@ -0,0 +1,116 @@
+use anyhow::{bail, Context, Result};
+
+use crate::config::Config;
+
+/// Identifies the tool to the website's maintainers, as they ask of automated requests.
+const USER_AGENT: &str = "github.com/alarsyo/advent-of-code";
+
+/// Authenticated client for the Advent of Code website.
+pub(crate) struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    cookie: String,
+}
+
+impl Client {
+    pub(crate) fn new(config: &Config) -> Result<Self> {
+        Ok(Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            cookie: format!("session={}", config.session()?),
+        })
+    }
+
+    /// Fetches `path` (e.g. `/2021/day/1/input`) and returns the body of the response.
+    pub(crate) fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        let res = self.agent.get(&url).set("Cookie", &self.cookie).call();
+
+        body(&url, res)
+    }
+
+    /// Posts `form` to `path` and returns the body of the response.
+    pub(crate) fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        let res = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie)
+            .send_form(form);
+
+        body(&url, res)
+    }
+}
+
+fn body(url: &str, res: Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match res {
+        Ok(res) => res
+            .into_string()
+            .with_context(|| format!("couldn't read response from `{}'", url)),
+        Err(ureq::Error::Status(code @ (400 | 401 | 403), _)) => bail!(
+            "`{}' answered {}, the session cookie is probably invalid or expired",
+            url,
+            code
+        ),
+        Err(ureq::Error::Status(code, _)) => bail!("`{}' answered {}", url, code),
+        Err(err) => Err(err).with_context(|| format!("couldn't reach `{}'", url)),
+    }
+}
+
+/// A stand-in for the website, answering a single request.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    use crate::config::Config;
+
+    /// Serves one request with `status` and `body`. Returns a config pointing to the server, and
+    /// a handle giving back the raw request once it's been served.
+    pub(crate) fn serve_once(status: u16, body: &str) -> (Config, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let body = body.to_string();
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} Whatever\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            request
+        });
+
+        let config = Config {
+            session: Some("c0ffee".to_string()),
+            base_url: format!("http://127.0.0.1:{}", port),
+        };
+
+        (config, handle)
+    }
+}
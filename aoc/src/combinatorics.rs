@@ -0,0 +1,276 @@
+//! Lazy permutations, combinations and cartesian products.
+
+/// All the orderings of `items`, generated with Heap's algorithm: each permutation differs from
+/// the previous one by a single swap. Starts with `items` as given.
+pub fn permutations<T: Clone>(items: &[T]) -> Permutations<T> {
+    Permutations {
+        items: items.to_vec(),
+        counters: vec![0; items.len()],
+        index: 1,
+        first: true,
+    }
+}
+
+pub struct Permutations<T> {
+    items: Vec<T>,
+    /// Heap's algorithm state, replacing the call stack of the recursive version.
+    counters: Vec<usize>,
+    index: usize,
+    first: bool,
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.first {
+            self.first = false;
+            return Some(self.items.clone());
+        }
+
+        while self.index < self.items.len() {
+            let i = self.index;
+            if self.counters[i] < i {
+                if i.is_multiple_of(2) {
+                    self.items.swap(0, i);
+                } else {
+                    self.items.swap(self.counters[i], i);
+                }
+                self.counters[i] += 1;
+                self.index = 1;
+
+                return Some(self.items.clone());
+            }
+
+            self.counters[i] = 0;
+            self.index += 1;
+        }
+
+        None
+    }
+}
+
+/// All the ways to choose `k` of `items`, keeping their order, in lexicographic order of their
+/// positions.
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Combinations<T> {
+    Combinations {
+        items: items.to_vec(),
+        indices: (0..k).collect(),
+        done: k > items.len(),
+    }
+}
+
+pub struct Combinations<T> {
+    items: Vec<T>,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+
+        let combination = self
+            .indices
+            .iter()
+            .map(|&i| self.items[i].clone())
+            .collect();
+
+        // advance the rightmost index which can still move, resetting the ones after it
+        let (n, k) = (self.items.len(), self.indices.len());
+        match (0..k).rev().find(|&i| self.indices[i] < n - k + i) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+
+        Some(combination)
+    }
+}
+
+/// The cartesian product of `N` iterables, the last one varying fastest:
+/// `product([0..2, 0..3])` yields `[0, 0], [0, 1], [0, 2], [1, 0]...`
+pub fn product<I, const N: usize>(iterables: [I; N]) -> Product<I::IntoIter, N>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: Clone,
+{
+    let originals = iterables.map(IntoIterator::into_iter);
+
+    Product {
+        iters: originals.clone(),
+        originals,
+        current: None,
+        done: false,
+    }
+}
+
+pub struct Product<I: Iterator, const N: usize> {
+    originals: [I; N],
+    iters: [I; N],
+    current: Option<[I::Item; N]>,
+    done: bool,
+}
+
+impl<I, const N: usize> Iterator for Product<I, N>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<[I::Item; N]> {
+        if self.done {
+            return None;
+        }
+
+        let current = match &mut self.current {
+            Some(current) => current,
+            None => {
+                let mut first = Vec::with_capacity(N);
+                for iter in &mut self.iters {
+                    match iter.next() {
+                        Some(item) => first.push(item),
+                        None => {
+                            self.done = true;
+                            return None;
+                        }
+                    }
+                }
+                let first: [I::Item; N] = first.try_into().ok()?;
+                self.current = Some(first.clone());
+
+                return Some(first);
+            }
+        };
+
+        // like an odometer: advance the last iterator, carrying over to the previous ones when
+        // they run out
+        for i in (0..N).rev() {
+            if let Some(item) = self.iters[i].next() {
+                current[i] = item;
+                let restarted = self.iters[i + 1..].iter_mut().zip(&self.originals[i + 1..]);
+                for (item, (iter, original)) in current[i + 1..].iter_mut().zip(restarted) {
+                    *iter = original.clone();
+                    *item = iter.next()?;
+                }
+
+                return Some(current.clone());
+            }
+        }
+
+        self.done = true;
+        None
+    }
+}
+
+/// Each unordered pair of distinct elements of `items`, in order: `pairs(0..3)` yields
+/// `(0, 1), (0, 2), (1, 2)`.
+pub fn pairs<I>(items: I) -> Pairs<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: Clone,
+{
+    let outer = items.into_iter();
+
+    Pairs {
+        inner: outer.clone(),
+        outer,
+        first: None,
+    }
+}
+
+pub struct Pairs<I: Iterator> {
+    outer: I,
+    inner: I,
+    first: Option<I::Item>,
+}
+
+impl<I> Iterator for Pairs<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(first) = &self.first {
+                if let Some(second) = self.inner.next() {
+                    return Some((first.clone(), second));
+                }
+            }
+
+            self.first = Some(self.outer.next()?);
+            self.inner = self.outer.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heap_permutations() {
+        let all: Vec<_> = permutations(&[1, 2, 3]).collect();
+        assert_eq!(all.len(), 6);
+        assert_eq!(all[0], vec![1, 2, 3]);
+
+        let mut sorted = all.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 6);
+
+        assert_eq!(permutations(&[0; 5]).count(), 120);
+        assert_eq!(
+            permutations::<u8>(&[]).collect::<Vec<_>>(),
+            vec![Vec::<u8>::new()]
+        );
+    }
+
+    #[test]
+    fn k_combinations() {
+        assert_eq!(
+            combinations(&['a', 'b', 'c', 'd'], 2)
+                .map(|c| c.into_iter().collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ab", "ac", "ad", "bc", "bd", "cd"]
+        );
+        assert_eq!(combinations(&[1, 2, 3], 3).count(), 1);
+        assert_eq!(combinations(&[1, 2, 3], 0).count(), 1);
+        assert_eq!(combinations(&[1, 2, 3], 4).count(), 0);
+        assert_eq!(combinations(&[0; 10], 4).count(), 210);
+    }
+
+    #[test]
+    fn cartesian_product() {
+        assert_eq!(
+            product([0..2, 0..3]).collect::<Vec<_>>(),
+            vec![[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2]]
+        );
+        assert_eq!(product([-1..=1, -1..=1, -1..=1, -1..=1]).count(), 81);
+        assert_eq!(product([0..2, 0..0]).count(), 0);
+        assert_eq!(product::<std::ops::Range<u8>, 0>([]).count(), 1);
+    }
+
+    #[test]
+    fn unordered_pairs() {
+        assert_eq!(
+            pairs(0..4).collect::<Vec<_>>(),
+            vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]
+        );
+        assert_eq!(pairs(&["a", "b"]).collect::<Vec<_>>(), vec![(&"a", &"b")]);
+        assert_eq!(pairs(0..1).count(), 0);
+    }
+}
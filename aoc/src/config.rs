@@ -0,0 +1,130 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings for talking to the Advent of Code website.
+///
+/// They're read from `$AOC_CONFIG`, or `$XDG_CONFIG_HOME/aoc/config.toml` (defaulting to
+/// `~/.config/aoc/config.toml`):
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// # useful to test against a local server
+/// base_url = "http://localhost:8080"
+/// ```
+///
+/// The `AOC_SESSION` and `AOC_BASE_URL` environment variables take precedence over the file.
+pub(crate) struct Config {
+    pub(crate) session: Option<String>,
+    pub(crate) base_url: String,
+}
+
+impl Config {
+    pub(crate) fn load() -> Result<Self> {
+        let mut config = match config_path() {
+            Some(path) => match fs::read_to_string(&path) {
+                Ok(content) => {
+                    Self::parse(&content).with_context(|| format!("in `{}'", path.display()))?
+                }
+                Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+                Err(err) => {
+                    return Err(err).with_context(|| format!("couldn't read `{}'", path.display()))
+                }
+            },
+            None => Self::default(),
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+
+        Ok(config)
+    }
+
+    fn parse(s: &str) -> Result<Self> {
+        let table: toml::Table = s.parse().context("couldn't parse config")?;
+        let mut config = Self::default();
+
+        for (key, value) in table {
+            let value = match value {
+                toml::Value::String(value) => value,
+                _ => bail!("`{}' should be a string", key),
+            };
+
+            match key.as_str() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                _ => bail!("unknown setting `{}'", key),
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub(crate) fn session(&self) -> Result<&str> {
+        self.session
+            .as_deref()
+            .context("no session cookie, set AOC_SESSION or `session' in ~/.config/aoc/config.toml")
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+/// Where wrong answers are logged, as they're tied to the account's inputs rather than the
+/// repository.
+pub(crate) fn guesses_path() -> Option<PathBuf> {
+    let cache_dir = match env::var_os("XDG_CACHE_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("HOME")?).join(".cache"),
+    };
+
+    Some(cache_dir.join("aoc").join("wrong_answers"))
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("AOC_CONFIG") {
+        return Some(path.into());
+    }
+
+    let config_dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(config_dir.join("aoc").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let config =
+            Config::parse("session = \"abc\"\nbase_url = \"http://localhost:8080/\"").unwrap();
+
+        assert_eq!(config.session().unwrap(), "abc");
+        assert_eq!(config.base_url, "http://localhost:8080/");
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Config::parse("sesion = \"abc\"").is_err());
+        assert!(Config::parse("session = 42").is_err());
+    }
+}
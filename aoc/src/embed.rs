@@ -0,0 +1,159 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::answers::Answers;
+
+/// Generates the files included by [`year!`](crate::year) for the year crate being built.
+///
+/// This is meant to be called from a year crate's build script.
+pub fn build() -> Result<()> {
+    let manifest_dir =
+        PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").context("CARGO_MANIFEST_DIR isn't set")?);
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").context("OUT_DIR isn't set")?);
+
+    let days = days(&manifest_dir)?;
+
+    let embedded = inputs(&manifest_dir, &out_dir, &days)?;
+    year(&out_dir, &days)?;
+    answer_tests(&manifest_dir, &out_dir, &embedded)?;
+
+    Ok(())
+}
+
+/// Finds the `src/dayNN.rs` modules of the crate, in order.
+fn days(manifest_dir: &Path) -> Result<Vec<u8>> {
+    let src_dir = manifest_dir.join("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut days = Vec::new();
+    for entry in fs::read_dir(&src_dir).context("couldn't read source directory")? {
+        let name = entry?.file_name();
+        let name = name.to_string_lossy();
+
+        if let Some(day) = name
+            .strip_prefix("day")
+            .and_then(|name| name.strip_suffix(".rs"))
+            .filter(|day| day.len() == 2 && day.bytes().all(|b| b.is_ascii_digit()))
+        {
+            days.push(day.parse()?);
+        }
+    }
+    days.sort_unstable();
+
+    Ok(days)
+}
+
+/// Generates `$OUT_DIR/inputs.rs`.
+///
+/// The generated file declares a `DAYNN: Option<&str>` constant for every day of the crate. It
+/// embeds `input/dayNN.txt` when that file exists and isn't empty, and is `None` otherwise, so
+/// that a crate missing some of its inputs still builds. Setting `AOC_NO_INPUTS` builds the crate
+/// as if none of them existed.
+///
+/// Returns the days whose input was embedded.
+fn inputs(manifest_dir: &Path, out_dir: &Path, days: &[u8]) -> Result<Vec<u8>> {
+    let input_dir = manifest_dir.join("input");
+    println!("cargo:rerun-if-changed={}", input_dir.display());
+    println!("cargo:rerun-if-env-changed=AOC_NO_INPUTS");
+
+    let no_inputs = env::var_os("AOC_NO_INPUTS").is_some_and(|v| !v.is_empty());
+
+    let mut embedded = Vec::new();
+    let mut generated = String::new();
+    for &day in days {
+        let input = input_dir.join(format!("day{:02}.txt", day));
+        // `aoc new` creates an empty placeholder until the input is fetched
+        let available = fs::metadata(&input).is_ok_and(|m| m.is_file() && m.len() > 0);
+        if available && !no_inputs {
+            embedded.push(day);
+            writeln!(
+                generated,
+                "pub const DAY{:02}: Option<&str> = Some(include_str!({:?}));",
+                day, input
+            )?;
+        } else {
+            writeln!(generated, "pub const DAY{:02}: Option<&str> = None;", day)?;
+        }
+    }
+
+    fs::write(out_dir.join("inputs.rs"), generated).context("couldn't write inputs.rs")?;
+
+    Ok(embedded)
+}
+
+/// Generates `$OUT_DIR/year.rs`, declaring the crate's `YEAR` table with one entry per day. The
+/// solution of `src/dayNN.rs` is expected to be a `DayNN` type implementing
+/// [`Solution`](crate::Solution).
+fn year(out_dir: &Path, days: &[u8]) -> Result<()> {
+    let name = env::var("CARGO_PKG_NAME").context("CARGO_PKG_NAME isn't set")?;
+    let year: u16 = name
+        .strip_prefix("aoc")
+        .and_then(|year| year.parse().ok())
+        .with_context(|| format!("crate `{}' should be named after its year", name))?;
+
+    let mut generated = String::new();
+    writeln!(generated, "/// All the days solved in {}.", year)?;
+    writeln!(generated, "pub const YEAR: aoc::Year = aoc::Year {{")?;
+    writeln!(generated, "    year: {},", year)?;
+    writeln!(generated, "    days: &[")?;
+    for day in days {
+        writeln!(
+            generated,
+            "        aoc::Day {{ day: {}, solution: &day{:02}::Day{:02}, input: inputs::DAY{:02} }},",
+            day, day, day, day
+        )?;
+    }
+    writeln!(generated, "    ],")?;
+    writeln!(generated, "}};")?;
+
+    fs::write(out_dir.join("year.rs"), generated).context("couldn't write year.rs")?;
+
+    Ok(())
+}
+
+/// Generates `$OUT_DIR/answer_tests.rs`.
+///
+/// The generated file holds a `dayNN_partN_real` test for every answer stored in the crate's
+/// `answers.toml`, checking it against the embedded input. Parts marked as slow, and days whose
+/// input wasn't `embedded`, are ignored by default.
+fn answer_tests(manifest_dir: &Path, out_dir: &Path, embedded: &[u8]) -> Result<()> {
+    let path = manifest_dir.join("answers.toml");
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let answers = Answers::load(&path)?;
+
+    let mut generated = String::new();
+    for day in answers.days() {
+        for part in [1, 2] {
+            let expected = match answers.get(day, part)? {
+                Some(expected) => expected,
+                None => continue,
+            };
+
+            writeln!(generated, "#[test]")?;
+            if !embedded.contains(&day) {
+                writeln!(
+                    generated,
+                    "#[ignore = \"input unavailable, input/day{:02}.txt wasn't embedded\"]",
+                    day
+                )?;
+            } else if answers.is_slow(day, part) {
+                writeln!(generated, "#[ignore]")?;
+            }
+            writeln!(
+                generated,
+                "fn day{:02}_part{}_real() {{ aoc::answers::check(&crate::YEAR, {}, {}, {:?}); }}",
+                day, part, day, part, expected
+            )?;
+        }
+    }
+
+    fs::write(out_dir.join("answer_tests.rs"), generated)
+        .context("couldn't write answer_tests.rs")?;
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::html::{elements, text};
+use crate::{answers, year_dir};
+
+/// What's extracted from one part's description on a puzzle page.
+struct Part {
+    examples: Vec<String>,
+    /// The last emphasized code in the description, which usually is the example's answer.
+    answer: Option<String>,
+}
+
+/// Lists the examples found in a saved puzzle page, or saves example `pick` to
+/// `input/dayNN_provided.txt` and records the answers going with it in `answers.toml`.
+pub(crate) fn examples(
+    year: u16,
+    day: u8,
+    page: &Path,
+    pick: Option<usize>,
+    force: bool,
+) -> Result<()> {
+    let html =
+        fs::read_to_string(page).with_context(|| format!("couldn't read `{}'", page.display()))?;
+    let parts = parse_page(&html);
+    if parts.is_empty() {
+        bail!(
+            "`{}' doesn't look like a puzzle page, no description found",
+            page.display()
+        );
+    }
+
+    // each example along with the part describing it
+    let examples: Vec<(usize, &String)> = parts
+        .iter()
+        .enumerate()
+        .flat_map(|(i, p)| p.examples.iter().map(move |e| (i + 1, e)))
+        .collect();
+
+    let pick = match pick {
+        Some(pick) => pick,
+        None => {
+            for (i, (part, example)) in examples.iter().enumerate() {
+                println!("example {} (part {}):\n{}", i + 1, part, example);
+            }
+            for (i, part) in parts.iter().enumerate() {
+                if let Some(answer) = &part.answer {
+                    println!("part {} answer: {}", i + 1, answer);
+                }
+            }
+            println!("\nsave one of them with `--pick <n>'");
+            return Ok(());
+        }
+    };
+
+    let (_, example) = pick
+        .checked_sub(1)
+        .and_then(|i| examples.get(i))
+        .with_context(|| format!("there are {} examples, can't pick {}", examples.len(), pick))?;
+
+    let dir = year_dir(year);
+
+    let path = dir
+        .join("input")
+        .join(format!("day{:02}_provided.txt", day));
+    let saved = fs::metadata(&path).is_ok_and(|m| m.len() > 0);
+    if saved && !force {
+        bail!(
+            "`{}' already exists, use --force to replace it",
+            path.display()
+        );
+    }
+    fs::write(&path, example).with_context(|| format!("couldn't write `{}'", path.display()))?;
+    println!("saved example {} to `{}'", pick, path.display());
+
+    let answers_path = dir.join("answers.toml");
+    for (part, answer) in answers_for(&parts, pick - 1) {
+        answers::record_provided(&answers_path, day, part, answer)?;
+        println!(
+            "recorded `{}' as the example answer to part {}",
+            answer, part
+        );
+    }
+
+    Ok(())
+}
+
+/// The answers going with the example at `index`, counting the examples of all parts: the answer
+/// of the part describing it, and those of the following parts which have no example of their
+/// own, since they reuse the previous one.
+fn answers_for(parts: &[Part], index: usize) -> Vec<(u8, &str)> {
+    let mut first = 0;
+    let owner = parts.iter().position(|p| {
+        first += p.examples.len();
+        index < first
+    });
+    let owner = match owner {
+        Some(owner) => owner,
+        None => return Vec::new(),
+    };
+
+    parts
+        .iter()
+        .enumerate()
+        .skip(owner)
+        .take_while(|&(i, p)| i == owner || p.examples.is_empty())
+        .filter_map(|(i, p)| Some((i as u8 + 1, p.answer.as_deref()?)))
+        .collect()
+}
+
+fn parse_page(html: &str) -> Vec<Part> {
+    elements(html, "<article class=\"day-desc\">", "</article>")
+        .into_iter()
+        .map(|article| Part {
+            examples: elements(article, "<pre><code>", "</code></pre>")
+                .into_iter()
+                .map(text)
+                .collect(),
+            answer: elements(article, "<code><em>", "</em></code>")
+                .into_iter()
+                .map(text)
+                .next_back(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54697</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+<em>x</em> &lt;-&gt; y &amp;&amp; z
+</code></pre>
+<p>Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn parse() {
+        let parts = parse_page(PAGE);
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(
+            parts[0].examples,
+            vec!["1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"]
+        );
+        assert_eq!(parts[0].answer.as_deref(), Some("142"));
+        assert_eq!(parts[1].examples, vec!["two1nine\nx <-> y && z\n"]);
+        assert_eq!(parts[1].answer.as_deref(), Some("281"));
+    }
+
+    #[test]
+    fn picked_answers() {
+        let parts = parse_page(PAGE);
+
+        // part 2 has its own example, 281 isn't the answer to the first one
+        assert_eq!(answers_for(&parts, 0), vec![(1, "142")]);
+        assert_eq!(answers_for(&parts, 1), vec![(2, "281")]);
+        assert_eq!(answers_for(&parts, 2), vec![]);
+
+        // a part 2 without an example reuses the one of part 1
+        let page = PAGE.replace("<pre><code>two1nine", "<p>two1nine");
+        let parts = parse_page(&page);
+        assert_eq!(answers_for(&parts, 0), vec![(1, "142"), (2, "281")]);
+    }
+
+    #[test]
+    fn no_answer() {
+        let parts = parse_page("<article class=\"day-desc\"><p>Nothing here</p></article>");
+
+        assert_eq!(parts.len(), 1);
+        assert!(parts[0].examples.is_empty());
+        assert_eq!(parts[0].answer, None);
+    }
+}
@@ -0,0 +1,147 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use anyhow::anyhow;
+
+/// Why a day couldn't be parsed, or one of its parts couldn't be solved.
+pub enum Failure {
+    Error(anyhow::Error),
+    Panic(String),
+    /// The solution hit a `todo!()` or `unimplemented!()`.
+    Todo,
+    /// The day didn't finish in the allotted time.
+    Timeout(Duration),
+}
+
+impl Failure {
+    /// Short status to show in place of an answer.
+    pub fn status(&self) -> &'static str {
+        match self {
+            Failure::Error(_) => "FAIL",
+            Failure::Panic(_) => "PANIC",
+            Failure::Todo => "TODO",
+            Failure::Timeout(_) => "TIMEOUT",
+        }
+    }
+
+    pub fn into_error(self) -> anyhow::Error {
+        match self {
+            Failure::Error(err) => err,
+            failure => anyhow!("{}", failure),
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(err) => write!(f, "{:#}", err),
+            Failure::Panic(msg) => write!(f, "panicked: {}", msg),
+            Failure::Todo => write!(f, "not yet implemented"),
+            Failure::Timeout(timeout) => write!(f, "didn't finish within {:?}", timeout),
+        }
+    }
+}
+
+thread_local! {
+    /// Where the last panic on this thread happened, recorded by the hook set in [`quiet_panics`].
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+
+    /// Whether this thread is running code under [`catch`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook so that panics caught by [`catch`] aren't printed to stderr, only
+/// recording where they happened: they are reported as part of the results instead. Other panics
+/// still go through the previous hook.
+#[cfg(any(feature = "cli", test))]
+pub(crate) fn quiet_panics() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if CATCHING.with(Cell::get) {
+            let location = info.location().map(ToString::to_string);
+            PANIC_LOCATION.with(|l| *l.borrow_mut() = location);
+        } else {
+            previous(info);
+        }
+    }));
+}
+
+/// Runs `f`, turning its errors and panics into a [`Failure`].
+pub(crate) fn catch<T>(f: impl FnOnce() -> anyhow::Result<T>) -> Result<T, Failure> {
+    let was_catching = CATCHING.with(|c| c.replace(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|c| c.set(was_catching));
+
+    match res {
+        Ok(res) => res.map_err(Failure::Error),
+        Err(payload) => Err(from_panic(payload)),
+    }
+}
+
+fn from_panic(payload: Box<dyn Any + Send>) -> Failure {
+    let msg = if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic payload".to_string()
+    };
+
+    // these are the messages of `todo!()` and `unimplemented!()`, possibly followed by a custom
+    // one
+    if msg.starts_with("not yet implemented") || msg.starts_with("not implemented") {
+        return Failure::Todo;
+    }
+
+    match PANIC_LOCATION.with(|l| l.borrow_mut().take()) {
+        Some(location) => Failure::Panic(format!("{} at {}", msg, location)),
+        None => Failure::Panic(msg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Once;
+
+    use anyhow::bail;
+
+    use super::*;
+
+    fn install() {
+        static HOOK: Once = Once::new();
+        HOOK.call_once(quiet_panics);
+    }
+
+    #[test]
+    fn errors() {
+        install();
+
+        assert_eq!(catch(|| Ok(3)).ok(), Some(3));
+        match catch::<()>(|| bail!("bad input")) {
+            Err(Failure::Error(err)) => assert_eq!(err.to_string(), "bad input"),
+            _ => panic!("expected an error"),
+        }
+    }
+
+    #[test]
+    fn panics() {
+        install();
+
+        match catch::<()>(|| panic!("index {} out of range", 3)) {
+            Err(Failure::Panic(msg)) => {
+                assert!(msg.starts_with("index 3 out of range at "), "{}", msg);
+                assert!(msg.contains("failure.rs:"), "{}", msg);
+            }
+            _ => panic!("expected a panic"),
+        }
+        assert!(matches!(catch::<()>(|| todo!()), Err(Failure::Todo)));
+        assert!(matches!(
+            catch::<()>(|| unimplemented!("part 2")),
+            Err(Failure::Todo)
+        ));
+    }
+}
@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::calendar;
+use crate::client::Client;
+use crate::config::Config;
+use crate::year_dir;
+
+/// Downloads the input for `day` of `year` to `aocYYYY/input/dayNN.txt`.
+pub(crate) fn fetch(config: &Config, year: u16, day: u8, force: bool) -> Result<()> {
+    let path = year_dir(year)
+        .join("input")
+        .join(format!("day{:02}.txt", day));
+
+    save_input(config, year, day, &path, force, calendar::now())?;
+    println!("saved input to `{}'", path.display());
+
+    Ok(())
+}
+
+fn save_input(
+    config: &Config,
+    year: u16,
+    day: u8,
+    path: &Path,
+    force: bool,
+    now: i64,
+) -> Result<()> {
+    // an empty file is only a placeholder, as created by `aoc new`
+    let fetched = fs::metadata(path).is_ok_and(|m| m.len() > 0);
+    if fetched && !force {
+        bail!(
+            "`{}' already exists, use --force to download it again",
+            path.display()
+        );
+    }
+    calendar::check_unlocked(year, day, now)?;
+
+    let input = Client::new(config)?.get(&format!("/{}/day/{}/input", year, day))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("couldn't create directory `{}'", dir.display()))?;
+    }
+    fs::write(path, input).with_context(|| format!("couldn't write `{}'", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::client::mock;
+
+    // the first puzzle of 2021 was unlocked at this time
+    const NOW: i64 = 1638334800;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir.join("input").join("day01.txt")
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (config, server) = mock::serve_once(200, "1\n2\n3\n");
+        let path = temp_path("downloads");
+
+        save_input(&config, 2021, 1, &path, false, NOW).unwrap();
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2021/day/1/input "));
+        assert!(request.contains("session=c0ffee"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+    }
+
+    #[test]
+    fn refuses_overwrite() {
+        let path = temp_path("overwrite");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "mine").unwrap();
+
+        let config = Config::default();
+        assert!(save_input(&config, 2021, 1, &path, false, NOW).is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "mine");
+
+        let (config, server) = mock::serve_once(200, "theirs");
+        save_input(&config, 2021, 1, &path, true, NOW).unwrap();
+        server.join().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "theirs");
+    }
+
+    #[test]
+    fn refuses_locked_puzzle() {
+        let path = temp_path("locked");
+        let config = Config::default();
+
+        assert!(save_input(&config, 2021, 2, &path, false, NOW).is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,434 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+
+/// Position of a cell, as `(x, y)` with `y` growing downwards.
+pub type Point = (usize, usize);
+
+/// Offsets to the 4 orthogonal neighbours of a cell.
+pub const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to the 8 neighbours of a cell, diagonals included.
+pub const DIRECTIONS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, typically parsed from a character map.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses a grid with one line per row, mapping each character to a cell with `cell`. All
+    /// lines must have the same length.
+    pub fn parse_with(s: &str, mut cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in s.lines().enumerate() {
+            let before = cells.len();
+            for c in line.chars() {
+                cells.push(cell(c).with_context(|| format!("on line {}", y + 1))?);
+            }
+
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => bail!(
+                    "line {} has {} cells, previous lines have {}",
+                    y + 1,
+                    len,
+                    width
+                ),
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Point) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at `(x, y)` of the plane tiled with copies of the grid.
+    ///
+    /// Panics if the grid is empty.
+    pub fn get_wrapping(&self, (x, y): (isize, isize)) -> &T {
+        assert!(!self.cells.is_empty(), "can't wrap around an empty grid");
+
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+
+        &self[(x, y)]
+    }
+
+    /// Moves from `point` by `offset`, if the result is still in the grid.
+    pub fn step(&self, point: Point, offset: (isize, isize)) -> Option<Point> {
+        self.bounds().step(point, offset)
+    }
+
+    /// The 4 orthogonal neighbours of `point` which are in the grid. The iterator doesn't borrow
+    /// the grid, which can be modified while iterating.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + 'static {
+        self.neighbours(point, &DIRECTIONS4)
+    }
+
+    /// Same as [`Grid::neighbours4`], diagonals included.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + 'static {
+        self.neighbours(point, &DIRECTIONS8)
+    }
+
+    fn neighbours(
+        &self,
+        point: Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'static {
+        let bounds = self.bounds();
+        offsets
+            .iter()
+            .filter_map(move |&offset| bounds.step(point, offset))
+    }
+
+    /// The points seen from `point` looking in `direction`, until the edge of the grid. `point`
+    /// itself isn't included.
+    pub fn ray(
+        &self,
+        point: Point,
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = Point> + 'static {
+        let bounds = self.bounds();
+        std::iter::successors(bounds.step(point, direction), move |&p| {
+            bounds.step(p, direction)
+        })
+    }
+
+    fn bounds(&self) -> Bounds {
+        Bounds {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// All the points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + 'static {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All the cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero width
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is out of a {}x{} grid",
+            x,
+            self.width,
+            self.height
+        );
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// The position of the first cell matching `predicate`, row by row.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Repeats the grid `across` times horizontally and `down` times vertically. Each copy of a
+    /// cell is made by `tile`, from the original cell and the position of its tile.
+    pub fn tile(&self, across: usize, down: usize, mut tile: impl FnMut(&T, Point) -> T) -> Self {
+        let (width, height) = (self.width * across, self.height * down);
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let cell = &self[(x % self.width, y % self.height)];
+                tile(cell, (x / self.width, y / self.height))
+            })
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+/// The size of a grid, for the iterators which shouldn't borrow it.
+#[derive(Clone, Copy)]
+struct Bounds {
+    width: usize,
+    height: usize,
+}
+
+impl Bounds {
+    fn step(self, (x, y): Point, (dx, dy): (isize, isize)) -> Option<Point> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+
+        Some((x, y))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Point) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is out of a {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Point) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is out of a {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Parses a grid of cells converted from each character with `TryFrom<char>`.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    anyhow::Error: From<T::Error>,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Grid::parse_with(s, |c| Ok(T::try_from(c)?))
+    }
+}
+
+/// Prints the grid one row per line, each cell being printed with its own `Display`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..\n.#.\n..#\n#.#\n";
+
+    fn grid() -> Grid<char> {
+        Grid::parse_with(MAP, Ok).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[(2, 3)], '#');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), MAP.trim_end());
+
+        assert!(Grid::parse_with("##\n#\n", Ok).is_err());
+        assert!(Grid::<char>::parse_with("ab", |_| bail!("nope")).is_err());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), &['.', '#', '.']);
+        assert_eq!(grid.rows().count(), 4);
+        assert_eq!(grid.column(2).collect::<String>(), "..##");
+        assert_eq!(
+            grid.columns()
+                .map(Iterator::collect::<String>)
+                .collect::<Vec<_>>(),
+            vec!["#..#", ".#..", "..##"]
+        );
+        assert_eq!(grid.position(|&c| c == '#'), Some((0, 0)));
+        assert_eq!(grid.iter().filter(|(_, &c)| c == '#').count(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is out of a 3x4 grid")]
+    fn column_out_of_grid() {
+        grid().column(3).count();
+    }
+
+    #[test]
+    #[should_panic(expected = "can't wrap around an empty grid")]
+    fn wrapping_empty_grid() {
+        Grid::new(0, 0, '.').get_wrapping((1, 1));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+
+        let mut corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        corner.sort_unstable();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 3)).count(), 3);
+    }
+
+    #[test]
+    fn rays() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 2)]
+        );
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+        assert_eq!(
+            grid.ray((1, 0), (0, 1))
+                .find(|&p| grid[p] == '#')
+                .map(|p| p.1),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn wrapping_and_tiling() {
+        let grid = grid();
+
+        assert_eq!(*grid.get_wrapping((3, 0)), '#');
+        assert_eq!(*grid.get_wrapping((-1, -1)), '#');
+        assert_eq!(*grid.get_wrapping((4, 5)), '#');
+
+        let tiled = grid.tile(2, 1, |&c, (tx, _)| if tx == 0 { c } else { 'x' });
+        assert_eq!((tiled.width(), tiled.height()), (6, 4));
+        assert_eq!(tiled.row(0), &['#', '.', '.', 'x', 'x', 'x']);
+    }
+
+    #[test]
+    fn from_str() {
+        #[derive(Debug, PartialEq)]
+        struct Tree(bool);
+
+        impl TryFrom<char> for Tree {
+            type Error = anyhow::Error;
+
+            fn try_from(c: char) -> Result<Self> {
+                match c {
+                    '#' => Ok(Tree(true)),
+                    '.' => Ok(Tree(false)),
+                    _ => bail!("unknown char `{}'", c),
+                }
+            }
+        }
+
+        let grid: Grid<Tree> = MAP.parse().unwrap();
+        assert_eq!(grid[(1, 1)], Tree(true));
+        assert!("#?".parse::<Grid<Tree>>().is_err());
+    }
+}
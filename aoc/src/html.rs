@@ -0,0 +1,42 @@
+//! Just enough HTML scraping for the puzzle pages, which are simple and regular.
+
+/// The content of each element between `open` and `close`.
+pub(crate) fn elements<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        match rest.find(close) {
+            Some(end) => {
+                found.push(&rest[..end]);
+                rest = &rest[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+
+    found
+}
+
+/// Text of an HTML fragment, without its tags.
+pub(crate) fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    // `&amp;` goes last, so that `&amp;lt;` becomes `&lt;`
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
@@ -1,30 +1,357 @@
 #![warn(clippy::explicit_iter_loop, clippy::redundant_closure_for_method_calls)]
 
-use std::env;
+use std::time::{Duration, Instant};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 
-pub type DayFunc = fn() -> Result<String>;
+pub mod answers;
+pub mod combinatorics;
+pub mod embed;
+mod failure;
+pub mod grid;
+pub mod math;
+pub mod mem;
+pub mod search;
+mod solution;
+pub mod union_find;
 
-pub fn run(days: &[DayFunc]) -> Result<()> {
-    let mut args = env::args();
-    args.next();
+// the command line tool, only built for `aoc-cli` so that the year crates and their build
+// scripts don't depend on an HTTP client
+#[cfg(feature = "cli")]
+mod baseline;
+#[cfg(feature = "cli")]
+mod calendar;
+#[cfg(feature = "cli")]
+mod cli;
+#[cfg(feature = "cli")]
+mod client;
+#[cfg(feature = "cli")]
+mod config;
+#[cfg(feature = "cli")]
+mod examples;
+#[cfg(feature = "cli")]
+mod fetch;
+#[cfg(feature = "cli")]
+mod html;
+#[cfg(feature = "cli")]
+mod output;
+#[cfg(feature = "cli")]
+mod pool;
+#[cfg(feature = "cli")]
+mod scaffold;
+#[cfg(feature = "cli")]
+mod submit;
+#[cfg(feature = "cli")]
+mod timing;
+#[cfg(feature = "cli")]
+mod verify;
+#[cfg(feature = "cli")]
+mod watch;
 
-    match args.next() {
-        Some(arg) => {
-            let day: usize = arg.parse().context("couldn't parse day number")?;
-            let res = days[day - 1]().context("error running day specified")?;
-            println!("{}", res);
+#[cfg(feature = "cli")]
+use cli::workspace_root;
+#[cfg(feature = "cli")]
+pub use cli::{run, year_dir};
+pub use failure::Failure;
+pub use grid::Grid;
+pub use solution::{Answer, DynSolution, Solution, Unimplemented};
+
+/// Declares the `YEAR` table of a year crate, listing the solution of each of its `dayNN`
+/// modules, along with tests checking the answers stored in its `answers.toml`.
+///
+/// The crate's build script must call [`embed::build`].
+#[macro_export]
+macro_rules! year {
+    () => {
+        mod inputs {
+            include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
         }
-        None => {
-            for (i, day) in days.iter().enumerate() {
-                let i = i + 1;
-                println!("day{}: ", i);
-                let res = day().with_context(|| format!("error running day {}", i))?;
-                println!("{}", res);
+
+        include!(concat!(env!("OUT_DIR"), "/year.rs"));
+
+        #[cfg(test)]
+        mod answer_tests {
+            include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
+        }
+    };
+}
+
+/// A day's solution, along with its puzzle input if it was available at compile time.
+pub struct Day {
+    pub day: u8,
+    pub solution: &'static dyn DynSolution,
+    pub input: Option<&'static str>,
+}
+
+/// All the days solved for a given year.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+/// The results of a day's parts, along with how long each step took and the memory it
+/// allocated. Memory is only measured once [`mem::enable`] was called.
+pub struct Solved {
+    pub parse: Duration,
+    pub parse_mem: mem::Usage,
+    pub parts: Vec<SolvedPart>,
+}
+
+pub struct SolvedPart {
+    pub part: u8,
+    pub answer: Result<Answer, Failure>,
+    pub duration: Duration,
+    pub mem: mem::Usage,
+}
+
+impl Day {
+    /// Parses `input` and solves each part, or only `part` if specified. Parts which aren't
+    /// implemented are skipped, unless `part` asked for one of them.
+    pub fn solve(&self, input: &str, part: Option<u8>) -> Result<Vec<(u8, Answer)>> {
+        let solved = self.solve_timed(input, part).map_err(Failure::into_error)?;
+
+        solved
+            .parts
+            .into_iter()
+            .map(|p| match p.answer {
+                Ok(answer) => Ok((p.part, answer)),
+                Err(failure) => Err(failure
+                    .into_error()
+                    .context(format!("couldn't solve part {}", p.part))),
+            })
+            .collect()
+    }
+
+    /// Same as [`Day::solve`], but also measures how long parsing and each part took. Each part
+    /// is isolated from the other: its errors and panics are reported in its result. Only a
+    /// failure to parse the input prevents solving the parts.
+    pub fn solve_timed(&self, input: &str, part: Option<u8>) -> Result<Solved, Failure> {
+        let measure = mem::Measure::start();
+        let start = Instant::now();
+        let input = failure::catch(|| self.solution.parse(input).context("couldn't parse input"))?;
+        let parse = start.elapsed();
+        let parse_mem = measure.stop();
+
+        let mut parts = Vec::with_capacity(2);
+
+        for p in [1, 2] {
+            if part.is_some_and(|part| part != p) {
+                continue;
+            }
+
+            let measure = mem::Measure::start();
+            let start = Instant::now();
+            let answer = failure::catch(|| match p {
+                1 => self.solution.part1(input.as_ref()),
+                _ => self.solution.part2(input.as_ref()),
+            });
+            let duration = start.elapsed();
+            let mem = measure.stop();
+
+            match answer {
+                // a missing part is only worth reporting if it was asked for
+                Err(Failure::Error(err)) if err.is::<Unimplemented>() && part.is_none() => {}
+                answer => parts.push(SolvedPart {
+                    part: p,
+                    answer,
+                    duration,
+                    mem,
+                }),
+            }
+        }
+
+        Ok(Solved {
+            parse,
+            parse_mem,
+            parts,
+        })
+    }
+}
+
+impl Year {
+    pub fn day(&self, day: u8) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.day == day)
+    }
+
+    /// Selects days from a comma separated list of day numbers, inclusive ranges such as `5-9`,
+    /// `all` and `latest`. The days are returned in order, without duplicates.
+    ///
+    /// A range selects the solved days it contains, it may span days which aren't solved.
+    pub fn select(&self, spec: &str) -> Result<Vec<&'static Day>> {
+        let mut selected = Vec::new();
+
+        for item in spec.split(',').map(str::trim) {
+            match item {
+                "all" => selected.extend(self.days),
+                "latest" => selected.extend(self.days.iter().max_by_key(|d| d.day)),
+                _ => match item.split_once('-') {
+                    Some((first, last)) => {
+                        let (first, last) = (self.parse_bound(first)?, self.parse_bound(last)?);
+                        if first > last {
+                            bail!("range `{}' is empty", item);
+                        }
+
+                        let len = selected.len();
+                        selected
+                            .extend(self.days.iter().filter(|d| (first..=last).contains(&d.day)));
+                        if selected.len() == len {
+                            bail!(
+                                "no day of {} is solved in `{}', solved days are: {}",
+                                self.year,
+                                item,
+                                self.solved_days()
+                            );
+                        }
+                    }
+                    None => {
+                        let day = self.parse_day(item)?;
+                        selected.extend(self.day(day));
+                    }
+                },
             }
         }
+
+        selected.sort_by_key(|d| d.day);
+        selected.dedup_by_key(|d| d.day);
+
+        Ok(selected)
+    }
+
+    /// Parses a day number, which must be one of the solved days.
+    fn parse_day(&self, day: &str) -> Result<u8> {
+        match parse_day_number(day)? {
+            day if self.day(day).is_some() => Ok(day),
+            day => bail!(
+                "day {} of {} isn't solved, solved days are: {}",
+                day,
+                self.year,
+                self.solved_days()
+            ),
+        }
     }
 
-    Ok(())
+    /// Parses the end of a range, which must be between day 1 and the last solved day.
+    fn parse_bound(&self, day: &str) -> Result<u8> {
+        let last = self.days.iter().map(|d| d.day).max().unwrap_or(0);
+
+        match parse_day_number(day)? {
+            day if (1..=last).contains(&day) => Ok(day),
+            day => bail!(
+                "day {} is out of range, days of {} are solved up to day {}",
+                day,
+                self.year,
+                last
+            ),
+        }
+    }
+
+    fn solved_days(&self) -> String {
+        self.days
+            .iter()
+            .map(|d| d.day.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn parse_day_number(day: &str) -> Result<u8> {
+    day.parse().with_context(|| {
+        format!(
+            "couldn't parse day `{}', expected a number, a range, `all' or `latest'",
+            day
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Dummy;
+
+    impl Solution for Dummy {
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Result<Answer> {
+            Ok(0.into())
+        }
+    }
+
+    const YEAR: Year = Year {
+        year: 2021,
+        days: &[
+            Day {
+                day: 1,
+                solution: &Dummy,
+                input: None,
+            },
+            Day {
+                day: 2,
+                solution: &Dummy,
+                input: None,
+            },
+            Day {
+                day: 3,
+                solution: &Dummy,
+                input: None,
+            },
+            Day {
+                day: 7,
+                solution: &Dummy,
+                input: None,
+            },
+        ],
+    };
+
+    #[test]
+    fn solve_missing_part() {
+        let day = &YEAR.days[0];
+
+        let parts = day.solve_timed("", None).ok().unwrap().parts;
+        assert_eq!(parts.iter().map(|p| p.part).collect::<Vec<_>>(), vec![1]);
+
+        let parts = day.solve_timed("", Some(2)).ok().unwrap().parts;
+        assert!(
+            matches!(&parts[..], [SolvedPart { part: 2, answer: Err(Failure::Error(err)), .. }]
+            if err.is::<Unimplemented>())
+        );
+
+        let err = day.solve("", Some(2)).unwrap_err();
+        assert_eq!(
+            format!("{:#}", err),
+            "couldn't solve part 2: part isn't implemented"
+        );
+    }
+
+    fn select(spec: &str) -> Result<Vec<u8>> {
+        Ok(YEAR.select(spec)?.iter().map(|d| d.day).collect())
+    }
+
+    #[test]
+    fn select_days() {
+        assert_eq!(select("2").unwrap(), vec![2]);
+        assert_eq!(select("7,1").unwrap(), vec![1, 7]);
+        assert_eq!(select("2-7").unwrap(), vec![2, 3, 7]);
+        assert_eq!(select("2-5").unwrap(), vec![2, 3]);
+        assert_eq!(select("1-2,2,3").unwrap(), vec![1, 2, 3]);
+        assert_eq!(select("all").unwrap(), vec![1, 2, 3, 7]);
+        assert_eq!(select("latest").unwrap(), vec![7]);
+    }
+
+    #[test]
+    fn select_invalid() {
+        let err = select("4").unwrap_err().to_string();
+        assert_eq!(err, "day 4 of 2021 isn't solved, solved days are: 1 2 3 7");
+
+        assert!(select("0").is_err());
+        assert!(select("1-8").is_err());
+        assert!(select("0-2").is_err());
+        assert!(select("5-6").is_err());
+        assert!(select("3-1").is_err());
+        assert!(select("first").is_err());
+    }
 }
@@ -0,0 +1,222 @@
+//! Number theory helpers: gcd and lcm, modular arithmetic, Chinese remainders and discrete
+//! logarithms.
+
+use std::collections::HashMap;
+use std::ops::{Div, Mul, Rem};
+
+/// Primitive integers, signed or not.
+pub trait Integer:
+    Copy + PartialEq + PartialOrd + Div<Output = Self> + Mul<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+
+    fn abs(self) -> Self;
+}
+
+macro_rules! integer {
+    (unsigned: $($u:ty),*; signed: $($i:ty),*) => {
+        $(
+            impl Integer for $u {
+                const ZERO: Self = 0;
+
+                fn abs(self) -> Self {
+                    self
+                }
+            }
+        )*
+        $(
+            impl Integer for $i {
+                const ZERO: Self = 0;
+
+                fn abs(self) -> Self {
+                    <$i>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+integer!(unsigned: u8, u16, u32, u64, u128, usize; signed: i8, i16, i32, i64, i128, isize);
+
+/// Greatest common divisor, always positive, or zero if both numbers are.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple, always positive, or zero if either number is.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Extended Euclidean algorithm: returns `(g, x, y)` such that `a * x + b * y = g`, with `g` the
+/// gcd of `a` and `b`.
+pub fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Inverse of `a` modulo `m`, if `a` and `m` are coprime.
+pub fn modinv(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = ext_gcd(a.into(), m.into());
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(m.into()) as i64)
+}
+
+/// `base` to the power of `exp`, modulo `m`.
+pub fn modpow(base: u64, mut exp: u64, m: u64) -> u64 {
+    let m = u128::from(m);
+    let mut base = u128::from(base) % m;
+    let mut result = 1 % m;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+/// Solves the system `x ≡ r (mod m)` for each `(r, m)` of `congruences`, the moduli needn't be
+/// coprime. Returns the smallest non negative solution, along with the lcm of the moduli: all the
+/// solutions are equal modulo that lcm. Returns `None` if the congruences are incompatible.
+///
+/// # Panics
+///
+/// If the lcm of the moduli doesn't fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0i128, 1i128);
+
+    for &(r, n) in congruences {
+        let (r, n) = (i128::from(r), i128::from(n));
+
+        // x + m * k ≡ r (mod n), which has solutions only if g divides r - x
+        let (g, p, _) = ext_gcd(m, n);
+        if (r - x) % g != 0 {
+            return None;
+        }
+
+        let n_g = n / g;
+        let k = ((r - x) / g % n_g * p).rem_euclid(n_g);
+        x += m * k;
+        m *= n_g;
+        x = x.rem_euclid(m);
+
+        assert!(
+            m <= i128::from(i64::MAX),
+            "lcm of the moduli overflows an i64"
+        );
+    }
+
+    Some((x as i64, m as i64))
+}
+
+/// Discrete logarithm, with the baby-step giant-step algorithm: the smallest `x` such that
+/// `base^x ≡ target (mod m)`, if any. `base` and `m` must be coprime.
+pub fn discrete_log(base: u64, target: u64, m: u64) -> Option<u64> {
+    if m == 1 {
+        return Some(0);
+    }
+
+    let n = (m as f64).sqrt().ceil() as u64;
+    let target = target % m;
+    let mulmod = |a: u64, b: u64| (u128::from(a) * u128::from(b) % u128::from(m)) as u64;
+
+    // baby steps: base^j for j < n, keeping the smallest j for each value
+    let mut baby = HashMap::with_capacity(n as usize);
+    let mut value = 1 % m;
+    for j in 0..n {
+        baby.entry(value).or_insert(j);
+        value = mulmod(value, base);
+    }
+
+    // giant steps: target * base^(-n*i)
+    let factor = modinv(i64::try_from(modpow(base, n, m)).ok()?, m.try_into().ok()?)? as u64;
+    let mut gamma = target;
+    for i in 0..n {
+        if let Some(j) = baby.get(&gamma) {
+            return Some(i * n + j);
+        }
+        gamma = mulmod(gamma, factor);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_lcm() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(gcd(0usize, 7), 7);
+        assert_eq!(gcd(0i8, 0), 0);
+        assert_eq!(lcm(4usize, 6), 12);
+        assert_eq!(lcm(-4i32, 6), 12);
+        assert_eq!(lcm(0u8, 3), 0);
+    }
+
+    #[test]
+    fn modular() {
+        let (g, x, y) = ext_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(modinv(3, 11), Some(4));
+        assert_eq!(modinv(-3, 11), Some(7));
+        assert_eq!(modinv(6, 9), None);
+
+        assert_eq!(modpow(4, 13, 497), 445);
+        assert_eq!(modpow(7, 0, 1), 0);
+        assert_eq!(modpow(u64::MAX, 2, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non coprime moduli
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn discrete_logarithm() {
+        assert_eq!(discrete_log(2, 1, 11), Some(0));
+        assert_eq!(discrete_log(2, 9, 11), Some(6));
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        // 2 only generates the quadratic residues modulo 7
+        assert_eq!(discrete_log(2, 3, 7), None);
+    }
+}
@@ -0,0 +1,175 @@
+//! Memory usage of solutions, measured by a global allocator counting allocations.
+//!
+//! The allocator has to be installed by the binary:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOC: aoc::mem::Counting = aoc::mem::Counting;
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use anyhow::{bail, Result};
+
+/// Counting is off until [`enable`] is called, so that it costs next to nothing otherwise.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// Wraps the system allocator, counting allocations once enabled.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ENABLED.load(Ordering::Relaxed) {
+            // memory allocated before counting was enabled isn't accounted for
+            let _ = CURRENT.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some(current.saturating_sub(layout.size() as u64))
+            });
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            grow(new_size, layout.size());
+        }
+        new
+    }
+}
+
+/// Counts an allocation of `size` bytes, replacing `freed` bytes.
+fn grow(size: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+
+    let update = |current: u64| (current + size as u64).saturating_sub(freed as u64);
+    let previous = CURRENT
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |c| Some(update(c)))
+        .unwrap();
+    PEAK.fetch_max(update(previous), Ordering::Relaxed);
+}
+
+/// Starts counting allocations. Fails if [`Counting`] isn't the global allocator.
+pub fn enable() -> Result<()> {
+    ENABLED.store(true, Ordering::Relaxed);
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    drop(std::hint::black_box(Box::new(0u64)));
+    if ALLOCATIONS.load(Ordering::Relaxed) == before {
+        ENABLED.store(false, Ordering::Relaxed);
+        bail!("allocations can't be counted, `aoc::mem::Counting' isn't the global allocator");
+    }
+
+    Ok(())
+}
+
+/// Memory allocated during a step of a solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    /// Total of all allocations, including memory freed since.
+    pub bytes: u64,
+    /// Highest amount of heap memory in use at once, on top of what was in use before the step.
+    /// This is only what went through the allocator, not the resident size of the process.
+    pub heap_peak: u64,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} total, {} heap peak",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.heap_peak)
+        )
+    }
+}
+
+/// Measures the memory allocated from its creation until [`Measure::stop`].
+pub(crate) struct Measure {
+    allocations: u64,
+    bytes: u64,
+    current: u64,
+}
+
+impl Measure {
+    pub(crate) fn start() -> Self {
+        let current = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+
+        Measure {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: ALLOCATED.load(Ordering::Relaxed),
+            current,
+        }
+    }
+
+    pub(crate) fn stop(self) -> Usage {
+        Usage {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            bytes: ALLOCATED.load(Ordering::Relaxed) - self.bytes,
+            heap_peak: PEAK.load(Ordering::Relaxed).saturating_sub(self.current),
+        }
+    }
+}
+
+/// A number of bytes, printed with a binary unit.
+struct Bytes(u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.0 MiB");
+    }
+}
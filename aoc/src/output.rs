@@ -0,0 +1,347 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{bail, Error};
+use serde_json::json;
+
+use crate::mem::Usage;
+use crate::timing::Summary;
+use crate::{Answer, Day, Failure, Solved};
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => bail!("format must be one of text, json or csv, got `{}'", s),
+        }
+    }
+}
+
+/// The result of one part, as printed in the machine readable formats.
+struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    status: String,
+    duration: Option<Duration>,
+    /// Only measured with `--mem`.
+    mem: Option<Usage>,
+}
+
+/// Prints the results of a run as they come, in the requested format.
+pub(crate) struct Output {
+    format: Format,
+    time: bool,
+    mem: bool,
+    year: u16,
+    summary: Summary,
+    records: Vec<Record>,
+    failures: usize,
+}
+
+impl Output {
+    pub(crate) fn new(format: Format, time: bool, mem: bool, year: u16) -> Self {
+        if format == Format::Csv {
+            let mem_columns = if mem {
+                ",allocations,bytes,heap_peak_bytes"
+            } else {
+                ""
+            };
+            println!("year,day,part,answer,status,duration_ns{}", mem_columns);
+        }
+
+        Output {
+            format,
+            time,
+            mem,
+            year,
+            summary: Summary::new(year),
+            records: Vec::new(),
+            failures: 0,
+        }
+    }
+
+    /// Reports the results of `day`. A failure which prevented solving the day is reported for
+    /// each of the requested `parts`.
+    pub(crate) fn day(&mut self, day: &Day, solved: &Result<Solved, Failure>, parts: &[u8]) {
+        self.failures += match solved {
+            Ok(solved) => {
+                self.summary.add(day.day, solved);
+                solved.parts.iter().filter(|p| p.answer.is_err()).count()
+            }
+            Err(_) => 1,
+        };
+
+        match self.format {
+            Format::Text => print_text(solved, self.time, self.mem),
+            Format::Json | Format::Csv => {
+                let records = records(self.year, day.day, solved, parts, self.mem);
+                if self.format == Format::Csv {
+                    records.iter().for_each(|r| print_csv(r, self.mem));
+                }
+                self.records.extend(records);
+            }
+        }
+    }
+
+    /// Prints what can only be printed once the run is over, and returns how many steps failed.
+    pub(crate) fn finish(self) -> usize {
+        match self.format {
+            Format::Text if self.time => print!("{}", self.summary.table()),
+            Format::Json => {
+                let records: Vec<_> = self.records.iter().map(to_json).collect();
+                println!("{}", serde_json::Value::Array(records));
+            }
+            _ => {}
+        }
+
+        self.failures
+    }
+
+    pub(crate) fn is_text(&self) -> bool {
+        self.format == Format::Text
+    }
+}
+
+fn print_text(solved: &Result<Solved, Failure>, time: bool, mem: bool) {
+    let solved = match solved {
+        Ok(solved) => solved,
+        Err(failure) => {
+            println!("{}: {}", failure.status(), failure);
+            return;
+        }
+    };
+
+    let stats = |duration: Duration, usage: Usage| {
+        let mut stats = String::new();
+        if time {
+            stats.push_str(&format!(" ({:.2?})", duration));
+        }
+        if mem {
+            stats.push_str(&format!(" [{}]", usage));
+        }
+        stats
+    };
+
+    match (time, mem) {
+        (true, false) => println!("parse: {:.2?}", solved.parse),
+        (_, true) => println!("parse:{}", stats(solved.parse, solved.parse_mem)),
+        _ => {}
+    }
+
+    for p in &solved.parts {
+        let duration = stats(p.duration, p.mem);
+
+        match &p.answer {
+            Ok(Answer::Grid(grid)) => println!("part {}:{}\n{}", p.part, duration, grid),
+            Ok(answer) => println!("part {}: {}{}", p.part, answer, duration),
+            Err(Failure::Todo) => println!("part {}: TODO{}", p.part, duration),
+            Err(failure) => println!(
+                "part {}: {}{}: {}",
+                p.part,
+                failure.status(),
+                duration,
+                failure
+            ),
+        }
+    }
+}
+
+fn records(
+    year: u16,
+    day: u8,
+    solved: &Result<Solved, Failure>,
+    parts: &[u8],
+    mem: bool,
+) -> Vec<Record> {
+    match solved {
+        Ok(solved) => solved
+            .parts
+            .iter()
+            .map(|p| Record {
+                year,
+                day,
+                part: p.part,
+                answer: p.answer.as_ref().ok().map(ToString::to_string),
+                status: match &p.answer {
+                    Ok(_) => "ok".to_string(),
+                    Err(failure) => failure.status().to_lowercase(),
+                },
+                duration: Some(p.duration),
+                mem: Some(p.mem).filter(|_| mem),
+            })
+            .collect(),
+        Err(failure) => parts
+            .iter()
+            .map(|&part| Record {
+                year,
+                day,
+                part,
+                answer: None,
+                status: failure.status().to_lowercase(),
+                duration: None,
+                mem: None,
+            })
+            .collect(),
+    }
+}
+
+fn to_json(record: &Record) -> serde_json::Value {
+    let mut json = json!({
+        "year": record.year,
+        "day": record.day,
+        "part": record.part,
+        "answer": record.answer,
+        "status": record.status,
+        "duration_ns": record.duration.map(|d| d.as_nanos() as u64),
+    });
+    if let Some(usage) = record.mem {
+        json["allocations"] = usage.allocations.into();
+        json["bytes"] = usage.bytes.into();
+        json["heap_peak_bytes"] = usage.heap_peak.into();
+    }
+
+    json
+}
+
+fn print_csv(record: &Record, mem: bool) {
+    println!("{}", csv_line(record, mem));
+}
+
+fn csv_line(record: &Record, mem: bool) -> String {
+    let mem_columns = match record.mem {
+        Some(usage) => format!(",{},{},{}", usage.allocations, usage.bytes, usage.heap_peak),
+        None if mem => ",,,".to_string(),
+        None => String::new(),
+    };
+
+    format!(
+        "{},{},{},{},{},{}{}",
+        record.year,
+        record.day,
+        record.part,
+        record.answer.as_deref().map(csv_field).unwrap_or_default(),
+        record.status,
+        record
+            .duration
+            .map(|d| d.as_nanos().to_string())
+            .unwrap_or_default(),
+        mem_columns,
+    )
+}
+
+/// Quotes `field` if needed, drawings span several lines for instance.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+    use crate::SolvedPart;
+
+    fn solved_day() -> Result<Solved, Failure> {
+        Ok(Solved {
+            parse: Duration::from_micros(5),
+            parse_mem: Usage::default(),
+            parts: vec![
+                SolvedPart {
+                    part: 1,
+                    answer: Ok(Answer::Grid("#.#\n.#.".to_string())),
+                    duration: Duration::from_nanos(1500),
+                    mem: Usage {
+                        allocations: 2,
+                        bytes: 64,
+                        heap_peak: 32,
+                    },
+                },
+                SolvedPart {
+                    part: 2,
+                    answer: Err(Failure::Error(anyhow!("no solution"))),
+                    duration: Duration::from_nanos(700),
+                    mem: Usage::default(),
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn csv_escaping() {
+        assert_eq!(csv_field("1234"), "1234");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("#.#\n.#."), "\"#.#\n.#.\"");
+    }
+
+    #[test]
+    fn solved_records() {
+        let solved = records(2020, 3, &solved_day(), &[1, 2], false);
+
+        assert_eq!(
+            solved
+                .iter()
+                .map(|r| csv_line(r, false))
+                .collect::<Vec<_>>(),
+            vec!["2020,3,1,\"#.#\n.#.\",ok,1500", "2020,3,2,,fail,700"]
+        );
+        assert_eq!(
+            to_json(&solved[1]),
+            json!({
+                "year": 2020,
+                "day": 3,
+                "part": 2,
+                "answer": null,
+                "status": "fail",
+                "duration_ns": 700,
+            })
+        );
+
+        let with_mem = records(2020, 3, &solved_day(), &[1, 2], true);
+        assert_eq!(
+            csv_line(&with_mem[0], true),
+            "2020,3,1,\"#.#\n.#.\",ok,1500,2,64,32"
+        );
+        assert_eq!(to_json(&with_mem[0])["heap_peak_bytes"], 32);
+    }
+
+    #[test]
+    fn failed_day_records() {
+        let failed = Err(Failure::Timeout(Duration::from_secs(1)));
+        let failed = records(2020, 15, &failed, &[1, 2], true);
+
+        assert_eq!(
+            failed.iter().map(|r| csv_line(r, true)).collect::<Vec<_>>(),
+            vec!["2020,15,1,,timeout,,,,", "2020,15,2,,timeout,,,,"]
+        );
+        assert_eq!(
+            to_json(&failed[0]),
+            json!({
+                "year": 2020,
+                "day": 15,
+                "part": 1,
+                "answer": null,
+                "status": "timeout",
+                "duration_ns": null,
+            })
+        );
+    }
+}
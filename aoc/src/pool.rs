@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Calls `f` on every item from `jobs` threads, and hands the results to `report` in the same
+/// order as `items`, as soon as they're available.
+pub(crate) fn run_ordered<'a, T, R, F, G>(items: &'a [T], jobs: usize, f: F, mut report: G)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&'a T) -> R + Sync,
+    G: FnMut(&'a T, R),
+{
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let tx = tx.clone();
+            let (next, f) = (&next, &f);
+
+            s.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                match items.get(i) {
+                    Some(item) => {
+                        if tx.send((i, f(item))).is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+            });
+        }
+        // only the workers hold a sender now, so the loop below ends once they're all done
+        drop(tx);
+
+        let mut pending = HashMap::new();
+        let mut to_report = 0;
+        for (i, res) in rx {
+            pending.insert(i, res);
+
+            while let Some(res) = pending.remove(&to_report) {
+                report(&items[to_report], res);
+                to_report += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn reports_in_order() {
+        let items: Vec<u64> = (0..8).collect();
+        let mut reported = Vec::new();
+
+        // the first items take the longest, so they finish after the ones following them
+        run_ordered(
+            &items,
+            4,
+            |&i| {
+                thread::sleep(Duration::from_millis((8 - i) * 10));
+                i * i
+            },
+            |&i, square| reported.push((i, square)),
+        );
+
+        assert_eq!(
+            reported,
+            items.iter().map(|&i| (i, i * i)).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::calendar;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const BUILD_TEMPLATE: &str = include_str!("../templates/build.rs.tmpl");
+
+/// Creates `day` of `year` in the workspace at `root`: its module from a template, declared in
+/// the year crate, along with empty input files. The year crate and its registration in `aoc-cli`
+/// are created too if the year is new.
+pub(crate) fn new_day(root: &Path, year: u16, day: u8) -> Result<()> {
+    if year < 2015 || day == 0 || day > calendar::puzzles_in(year) {
+        bail!("there's no puzzle for day {} of {}", day, year);
+    }
+
+    let year_dir = root.join(format!("aoc{}", year));
+    if !year_dir.exists() {
+        new_year(root, year)?;
+    }
+
+    let module = year_dir.join("src").join(format!("day{:02}.rs", day));
+    if module.exists() {
+        bail!("`{}' already exists", module.display());
+    }
+    write(&module, &fill(DAY_TEMPLATE, year, day))?;
+
+    let lib = year_dir.join("src").join("lib.rs");
+    let content = read(&lib)?;
+    write(&lib, &register_day(&content, day)?)?;
+
+    for name in [
+        format!("day{:02}.txt", day),
+        format!("day{:02}_provided.txt", day),
+    ] {
+        let path = year_dir.join("input").join(name);
+        if !path.exists() {
+            write(&path, "")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn new_year(root: &Path, year: u16) -> Result<()> {
+    let year_dir = root.join(format!("aoc{}", year));
+
+    write(&year_dir.join("Cargo.toml"), &fill(CARGO_TEMPLATE, year, 0))?;
+    write(&year_dir.join("build.rs"), BUILD_TEMPLATE)?;
+    write(
+        &year_dir.join("src").join("lib.rs"),
+        &fill(LIB_TEMPLATE, year, 0),
+    )?;
+
+    // make the new year available from the `aoc` binary and the benches
+    let cli = root.join("aoc-cli");
+
+    let manifest = cli.join("Cargo.toml");
+    let content = read(&manifest)?;
+    let dep = format!("aoc{} = {{ path = \"../aoc{}\" }}", year, year);
+    write(
+        &manifest,
+        &insert_sorted(&content, &dep, |l| l.starts_with("aoc20"))?,
+    )?;
+
+    let lib = cli.join("src").join("lib.rs");
+    let content = read(&lib)?;
+    let entry = format!("    aoc{}::YEAR,", year);
+    write(
+        &lib,
+        &insert_sorted(&content, &entry, |l| l.ends_with("::YEAR,"))?,
+    )?;
+
+    Ok(())
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("%YEAR%", &year.to_string())
+        .replace("%DAY%", &format!("{:02}", day))
+}
+
+/// Declares the module of `day` in the `lib.rs` of a year crate, which is enough for it to be
+/// part of the `YEAR` table.
+fn register_day(lib: &str, day: u8) -> Result<String> {
+    let module = format!("pub mod day{:02};", day);
+
+    if lib.contains("pub mod day") {
+        insert_sorted(lib, &module, |l| l.starts_with("pub mod day"))
+    } else {
+        // first day of the year
+        Ok(lib.replacen("aoc::year!", &format!("{}\n\naoc::year!", module), 1))
+    }
+}
+
+/// Inserts `line` among the consecutive lines matching `group`, keeping them sorted.
+fn insert_sorted(content: &str, line: &str, group: impl Fn(&str) -> bool) -> Result<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+
+    let first = lines
+        .iter()
+        .position(|l| group(l.trim_start()))
+        .with_context(|| format!("nowhere to insert `{}'", line.trim()))?;
+    let last = first
+        + lines[first..]
+            .iter()
+            .take_while(|l| group(l.trim_start()))
+            .count();
+
+    if lines[first..last].contains(&line) {
+        bail!("`{}' is already there", line.trim());
+    }
+    let pos = first + lines[first..last].partition_point(|&l| l < line);
+    lines.insert(pos, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("couldn't read `{}'", path.display()))
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("couldn't create directory `{}'", dir.display()))?;
+    }
+    fs::write(path, content).with_context(|| format!("couldn't write `{}'", path.display()))?;
+    println!("wrote `{}'", path.display());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_days() {
+        let lib = register_day(LIB_TEMPLATE, 5).unwrap();
+        assert_eq!(lib, "pub mod day05;\n\naoc::year!();\n");
+
+        let lib = register_day(&lib, 1).unwrap();
+        let lib = register_day(&lib, 3).unwrap();
+        assert_eq!(
+            lib,
+            "pub mod day01;\npub mod day03;\npub mod day05;\n\naoc::year!();\n"
+        );
+
+        assert!(register_day(&lib, 3).is_err());
+    }
+
+    #[test]
+    fn insert_years() {
+        let main = "let years = &[\n    aoc2015::YEAR,\n    aoc2020::YEAR,\n];\n";
+        let main = insert_sorted(main, "    aoc2018::YEAR,", |l| l.ends_with("::YEAR,")).unwrap();
+
+        assert_eq!(
+            main,
+            "let years = &[\n    aoc2015::YEAR,\n    aoc2018::YEAR,\n    aoc2020::YEAR,\n];\n"
+        );
+    }
+}
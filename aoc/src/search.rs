@@ -0,0 +1,226 @@
+//! Shortest path searches over implicit graphs: nodes can be of any type, their edges are given
+//! by a `successors` closure.
+//!
+//! Each search returns the cost of the path found along with the path itself, from the start node
+//! to the goal, both included.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Visited nodes, with the index of the node they were reached from and the cost to reach them.
+struct Visited<N, C> {
+    nodes: Vec<(N, Option<usize>, C)>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N, C> Visited<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy,
+{
+    fn new(start: N, zero: C) -> Self {
+        Visited {
+            nodes: vec![(start.clone(), None, zero)],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Walks back the parents of the node at `index` up to the start.
+    fn path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].0.clone()];
+        while let Some(parent) = self.nodes[index].1 {
+            path.push(self.nodes[parent].0.clone());
+            index = parent;
+        }
+        path.reverse();
+
+        path
+    }
+}
+
+/// Breadth first search, for graphs where all edges cost 1. The cost is the number of steps.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        let (node, _, steps) = visited.nodes[index].clone();
+        if success(&node) {
+            return Some((steps, visited.path(index)));
+        }
+
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = visited.indices.entry(next.clone()) {
+                entry.insert(visited.nodes.len());
+                queue.push_back(visited.nodes.len());
+                visited.nodes.push((next, Some(index), steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, `successors` giving each neighbour of a node with the cost of the edge
+/// to it. Costs must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// A* search, same as [`dijkstra`] but guided by a `heuristic` estimating the cost from a node to
+/// the goal. The path found is the shortest as long as the heuristic never overestimates.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new(start.clone(), C::default());
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), C::default(), 0)));
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let (node, _, known_cost) = visited.nodes[index].clone();
+        if cost > known_cost {
+            // a cheaper way to this node was found after this entry was queued
+            continue;
+        }
+
+        if success(&node) {
+            return Some((cost, visited.path(index)));
+        }
+
+        for (next, edge) in successors(&node) {
+            let next_cost = cost + edge;
+
+            let next_index = match visited.indices.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    let next_index = visited.nodes.len();
+                    entry.insert(next_index);
+                    visited.nodes.push((next.clone(), Some(index), next_cost));
+                    next_index
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    let (_, parent, known_cost) = &mut visited.nodes[next_index];
+                    if next_cost >= *known_cost {
+                        continue;
+                    }
+                    *parent = Some(index);
+                    *known_cost = next_cost;
+                    next_index
+                }
+            };
+
+            queue.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                next_index,
+            )));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.
+...#...";
+
+    fn maze() -> Grid<bool> {
+        Grid::parse_with(MAZE, |c| Ok(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn breadth_first() {
+        let maze = maze();
+        let goal: (usize, usize) = (6, 4);
+        let successors = |&p: &(usize, usize)| maze.neighbours4(p).filter(|&n| !maze[n]);
+
+        let (steps, path) = bfs((0, 0), successors, |&p| p == goal).unwrap();
+        assert_eq!(steps, 10);
+        assert_eq!(path.len(), 11);
+        assert_eq!((path[0], path[10]), ((0, 0), goal));
+        assert!(path
+            .windows(2)
+            .all(|w| maze.neighbours4(w[0]).any(|n| n == w[1])));
+
+        assert_eq!(bfs((0, 0), successors, |&p| p == (2, 0)), None);
+    }
+
+    #[test]
+    fn weighted() {
+        // the direct edge is more expensive than going around
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('d', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 3)]),
+            ('d', vec![]),
+        ]);
+        let successors = |n: &char| edges[n].clone();
+
+        assert_eq!(
+            dijkstra('a', successors, |&n| n == 'd'),
+            Some((6, vec!['a', 'b', 'c', 'd']))
+        );
+        assert_eq!(dijkstra('d', successors, |&n| n == 'a'), None);
+        assert_eq!(
+            dijkstra('a', successors, |&n| n == 'a'),
+            Some((0, vec!['a']))
+        );
+    }
+
+    #[test]
+    fn guided() {
+        let maze = maze();
+        let goal: (usize, usize) = (6, 4);
+        let successors = |&p: &(usize, usize)| {
+            maze.neighbours4(p)
+                .filter(|&n| !maze[n])
+                .map(|n| (n, 1usize))
+                .collect::<Vec<_>>()
+        };
+        let manhattan = |&(x, y): &(usize, usize)| goal.0.abs_diff(x) + goal.1.abs_diff(y);
+
+        let (cost, path) = astar((0, 0), successors, manhattan, |&p| p == goal).unwrap();
+        assert_eq!(cost, 10);
+        assert_eq!(path.len(), 11);
+        assert_eq!(
+            dijkstra((0, 0), successors, |&p| p == goal).map(|(cost, _)| cost),
+            Some(cost)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+
+use anyhow::{Context, Result};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    /// A multi-line drawing, usually letters to be read by a human.
+    Grid(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Str(s) | Answer::Grid(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+/// Error returned for a part which doesn't exist, or hasn't been written yet.
+#[derive(Debug)]
+pub struct Unimplemented;
+
+impl fmt::Display for Unimplemented {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part isn't implemented")
+    }
+}
+
+impl Error for Unimplemented {}
+
+/// A day's solution, split between parsing the input and solving each part.
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Err(Unimplemented.into())
+    }
+}
+
+/// Object safe version of [`Solution`], so that days can be stored in a table regardless of
+/// their input type.
+pub trait DynSolution: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    fn part1(&self, input: &dyn Any) -> Result<Answer>;
+
+    fn part2(&self, input: &dyn Any) -> Result<Answer>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<Answer> {
+        S::part1(downcast::<S>(input)?)
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<Answer> {
+        S::part2(downcast::<S>(input)?)
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input>
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .context("input wasn't parsed by this solution")
+}
@@ -0,0 +1,257 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::client::Client;
+use crate::config::{self, Config};
+use crate::html::{elements, text};
+use crate::{answers, year_dir, Answer, Day};
+
+/// What the website made of a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without telling in which direction.
+    Wrong,
+    /// Submitted too soon after a previous answer, for how long to wait.
+    Wait(String),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    fn parse(page: &str) -> Result<Self> {
+        let message = elements(page, "<article>", "</article>")
+            .into_iter()
+            .map(text)
+            .next()
+            .context("couldn't find the verdict in the response")?;
+
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if message.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            let wait = message
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map_or("a bit", |(wait, _)| wait);
+            Verdict::Wait(wait.to_string())
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            bail!("unexpected response: {}", message.trim());
+        };
+
+        Ok(verdict)
+    }
+
+    /// How the verdict is stored in the wrong answers log, for wrong answers.
+    fn logged(&self) -> Option<&'static str> {
+        match self {
+            Verdict::TooHigh => Some("high"),
+            Verdict::TooLow => Some("low"),
+            Verdict::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "that's the right answer!"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(wait) => write!(f, "answered too recently, wait {}", wait),
+            Verdict::WrongLevel => write!(f, "this part is already solved, or still locked"),
+        }
+    }
+}
+
+/// Solves `part` of `day` on `input`, and submits the answer. Correct answers are recorded in
+/// the year's `answers.toml`.
+pub(crate) fn submit(config: &Config, year: u16, day: &Day, part: u8, input: &str) -> Result<()> {
+    let answer = match day.solve(input, Some(part))?.pop() {
+        Some((_, Answer::Grid(grid))) => {
+            bail!(
+                "the answer is a drawing, read it and submit it by hand:\n{}",
+                grid
+            )
+        }
+        Some((_, answer)) => answer.to_string(),
+        None => bail!("part {} isn't implemented", part),
+    };
+    println!("submitting `{}'", answer);
+
+    let log = config::guesses_path().context("couldn't find where to log wrong answers")?;
+    let verdict = submit_answer(config, &log, year, day.day, part, &answer)?;
+
+    match verdict {
+        Verdict::Correct => {
+            println!("{}", verdict);
+            answers::record(&year_dir(year).join("answers.toml"), day.day, part, &answer)?;
+            Ok(())
+        }
+        verdict => bail!("{}", verdict),
+    }
+}
+
+/// Submits `answer`, unless it's known to be wrong from previous guesses logged at `log`. Wrong
+/// answers are added to the log.
+fn submit_answer(
+    config: &Config,
+    log: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict> {
+    check_guesses(log, year, day, part, answer)?;
+
+    let page = Client::new(config)?.post(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let verdict = Verdict::parse(&page)?;
+
+    if let Some(logged) = verdict.logged() {
+        if let Some(dir) = log.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("couldn't create directory `{}'", dir.display()))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log)
+            .with_context(|| format!("couldn't open `{}'", log.display()))?;
+        writeln!(file, "{} {} {} {} {}", year, day, part, logged, answer)
+            .with_context(|| format!("couldn't write to `{}'", log.display()))?;
+    }
+
+    Ok(verdict)
+}
+
+/// Refuses `answer` if it was already guessed, or if it's out of the bounds given by previous
+/// "too high" and "too low" verdicts.
+fn check_guesses(log: &Path, year: u16, day: u8, part: u8, answer: &str) -> Result<()> {
+    let content = match fs::read_to_string(log) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err).with_context(|| format!("couldn't read `{}'", log.display())),
+    };
+
+    let prefix = format!("{} {} {} ", year, day, part);
+    let number: Option<i128> = answer.parse().ok();
+
+    for line in content.lines() {
+        let (verdict, guess) = match line
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.split_once(' '))
+        {
+            Some(guess) => guess,
+            None => continue,
+        };
+
+        if guess == answer {
+            bail!("`{}' was already submitted, and was wrong", answer);
+        }
+
+        if let (Some(number), Ok(guess)) = (number, guess.parse::<i128>()) {
+            match verdict {
+                "high" if number >= guess => {
+                    bail!("`{}' is too high, `{}' already was", answer, guess)
+                }
+                "low" if number <= guess => {
+                    bail!("`{}' is too low, `{}' already was", answer, guess)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::client::mock;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><main>\n<article><p>{}</p></article>\n</main></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn verdicts() {
+        let parse = |message| Verdict::parse(&page(message)).unwrap();
+
+        assert_eq!(
+            parse("That's the right answer! You are <em>one gold star</em> closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too high. Please wait one minute."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse("That's not the right answer. If you're stuck, ..."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            parse("You gave an answer too recently. You have 4m 32s left to wait."),
+            Verdict::Wait("4m 32s".to_string())
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level."),
+            Verdict::WrongLevel
+        );
+        assert!(Verdict::parse(&page("Something else")).is_err());
+    }
+
+    #[test]
+    fn logs_wrong_guesses() {
+        let log = env::temp_dir().join(format!("aoc-guesses-{}", std::process::id()));
+        let _ = fs::remove_file(&log);
+
+        let (config, server) = mock::serve_once(
+            200,
+            &page("That's not the right answer; your answer is too high."),
+        );
+        let verdict = submit_answer(&config, &log, 2021, 1, 2, "1000").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(verdict, Verdict::TooHigh);
+        assert!(request.starts_with("POST /2021/day/1/answer "));
+        assert!(request.ends_with("level=2&answer=1000"));
+
+        // refused without reaching the server, which is gone anyway
+        assert!(submit_answer(&config, &log, 2021, 1, 2, "1000").is_err());
+        assert!(submit_answer(&config, &log, 2021, 1, 2, "1001").is_err());
+        assert!(check_guesses(&log, 2021, 1, 2, "999").is_ok());
+        assert!(check_guesses(&log, 2021, 1, 1, "1000").is_ok());
+
+        fs::remove_file(&log).unwrap();
+    }
+}
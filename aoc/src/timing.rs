@@ -0,0 +1,142 @@
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use crate::Solved;
+
+/// How long each day of a run took, printed as a table once the run is over.
+pub(crate) struct Summary {
+    year: u16,
+    rows: Vec<Row>,
+}
+
+struct Row {
+    day: u8,
+    parse: Duration,
+    parts: [Option<Duration>; 2],
+}
+
+impl Row {
+    fn total(&self) -> Duration {
+        self.parse + self.parts.iter().flatten().sum::<Duration>()
+    }
+}
+
+impl Summary {
+    pub(crate) fn new(year: u16) -> Self {
+        Summary {
+            year,
+            rows: Vec::new(),
+        }
+    }
+
+    pub(crate) fn add(&mut self, day: u8, solved: &Solved) {
+        let mut parts = [None; 2];
+        for p in &solved.parts {
+            parts[usize::from(p.part - 1)] = Some(p.duration);
+        }
+
+        self.rows.push(Row {
+            day,
+            parse: solved.parse,
+            parts,
+        });
+    }
+
+    /// Lays the rows out as a table, followed by the total of each column.
+    pub(crate) fn table(&self) -> String {
+        let mut table = vec![[
+            self.year.to_string(),
+            "parse".to_string(),
+            "part 1".to_string(),
+            "part 2".to_string(),
+            "total".to_string(),
+        ]];
+
+        for row in &self.rows {
+            table.push([
+                format!("day {}", row.day),
+                format_duration(Some(row.parse)),
+                format_duration(row.parts[0]),
+                format_duration(row.parts[1]),
+                format_duration(Some(row.total())),
+            ]);
+        }
+
+        let sum_part = |i: usize| self.rows.iter().filter_map(|r| r.parts[i]).sum();
+        table.push([
+            "total".to_string(),
+            format_duration(Some(self.rows.iter().map(|r| r.parse).sum())),
+            format_duration(Some(sum_part(0))),
+            format_duration(Some(sum_part(1))),
+            format_duration(Some(self.rows.iter().map(Row::total).sum())),
+        ]);
+
+        let mut widths = [0; 5];
+        for line in &table {
+            for (width, cell) in widths.iter_mut().zip(line) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut out = String::new();
+        for line in &table {
+            let mut cells = line.iter().zip(widths);
+            let (first, width) = cells.next().unwrap();
+            write!(out, "{:<width$}", first, width = width).unwrap();
+            for (cell, width) in cells {
+                write!(out, "  {:>width$}", cell, width = width).unwrap();
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => format!("{:.2?}", duration),
+        None => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem::Usage;
+    use crate::SolvedPart;
+
+    fn solved(parse: u64, parts: &[u64]) -> Solved {
+        Solved {
+            parse: Duration::from_millis(parse),
+            parse_mem: Usage::default(),
+            parts: parts
+                .iter()
+                .zip(1..)
+                .map(|(&duration, part)| SolvedPart {
+                    part,
+                    answer: Ok(0.into()),
+                    duration: Duration::from_millis(duration),
+                    mem: Usage::default(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn table() {
+        let mut summary = Summary::new(2020);
+        summary.add(1, &solved(1, &[2]));
+        summary.add(3, &solved(4, &[8, 16]));
+
+        assert_eq!(
+            summary.table(),
+            "\
+2020    parse   part 1   part 2    total
+day 1  1.00ms   2.00ms        -   3.00ms
+day 3  4.00ms   8.00ms  16.00ms  28.00ms
+total  5.00ms  10.00ms  16.00ms  31.00ms
+"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Disjoint sets, with union by size and path compression.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Union-find over the elements `0..len`, for when they can be numbered without hashing.
+#[derive(Debug, Clone)]
+pub struct IndexUnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl IndexUnionFind {
+    /// Creates `len` elements, each in its own set.
+    pub fn new(len: usize) -> Self {
+        IndexUnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            count: len,
+        }
+    }
+
+    /// Adds a new element in its own set, returning its index.
+    pub fn push(&mut self) -> usize {
+        let index = self.parent.len();
+        self.parent.push(index);
+        self.size.push(1);
+        self.count += 1;
+
+        index
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // path compression: point everything on the way directly to the root
+        let mut x = x;
+        while self.parent[x] != root {
+            (x, self.parent[x]) = (self.parent[x], root);
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns `false` if they already were the same
+    /// set, which makes Kruskal's algorithm a filter over the sorted edges.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.count -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the set containing `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// The representative of each set.
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter(|&(x, &parent)| x == parent)
+            .map(|(x, _)| x)
+    }
+
+    /// The elements of each set, in increasing order, sets being ordered by their smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut indices = HashMap::with_capacity(self.count);
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+
+        for x in 0..self.len() {
+            let root = self.find(x);
+            let index = *indices.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[index].push(x);
+        }
+
+        components
+    }
+}
+
+/// Union-find over any hashable elements, added as they are first seen.
+#[derive(Debug, Clone)]
+pub struct UnionFind<T> {
+    sets: IndexUnionFind,
+    elements: Vec<T>,
+    indices: HashMap<T, usize>,
+}
+
+impl<T: Hash + Eq + Clone> UnionFind<T> {
+    pub fn new() -> Self {
+        UnionFind {
+            sets: IndexUnionFind::new(0),
+            elements: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Adds `element` in its own set if it's new, returning its index in [`UnionFind::sets`].
+    pub fn insert(&mut self, element: T) -> usize {
+        if let Some(&index) = self.indices.get(&element) {
+            return index;
+        }
+
+        let index = self.sets.push();
+        self.elements.push(element.clone());
+        self.indices.insert(element, index);
+
+        index
+    }
+
+    /// The index of `element`, if it was added.
+    pub fn index(&self, element: &T) -> Option<usize> {
+        self.indices.get(element).copied()
+    }
+
+    /// The element at `index`.
+    pub fn element(&self, index: usize) -> &T {
+        &self.elements[index]
+    }
+
+    /// The underlying union-find over the indices of the elements.
+    pub fn sets(&mut self) -> &mut IndexUnionFind {
+        &mut self.sets
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.sets.count()
+    }
+
+    /// The representative of the set containing `element`, if it was added.
+    pub fn find(&mut self, element: &T) -> Option<&T> {
+        let index = self.index(element)?;
+        let root = self.sets.find(index);
+
+        Some(&self.elements[root])
+    }
+
+    /// Merges the sets containing `a` and `b`, adding them first if needed. Returns `false` if
+    /// they already were the same set.
+    pub fn union(&mut self, a: &T, b: &T) -> bool {
+        let a = self.insert(a.clone());
+        let b = self.insert(b.clone());
+
+        self.sets.union(a, b)
+    }
+
+    /// Whether `a` and `b` were added and are in the same set.
+    pub fn connected(&mut self, a: &T, b: &T) -> bool {
+        match (self.index(a), self.index(b)) {
+            (Some(a), Some(b)) => self.sets.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// Size of the set containing `element`, or 0 if it wasn't added.
+    pub fn size(&mut self, element: &T) -> usize {
+        match self.index(element) {
+            Some(index) => self.sets.size(index),
+            None => 0,
+        }
+    }
+
+    /// The elements of each set, in the order they were added.
+    pub fn components(&mut self) -> Vec<Vec<&T>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|set| set.into_iter().map(|i| &self.elements[i]).collect())
+            .collect()
+    }
+}
+
+impl<T: Hash + Eq + Clone> Default for UnionFind<T> {
+    fn default() -> Self {
+        UnionFind::new()
+    }
+}
+
+/// Adds each element in its own set.
+impl<T: Hash + Eq + Clone> FromIterator<T> for UnionFind<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut union_find = UnionFind::new();
+        for element in iter {
+            union_find.insert(element);
+        }
+
+        union_find
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indices() {
+        let mut sets = IndexUnionFind::new(6);
+        assert_eq!(sets.count(), 6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert_eq!(sets.count(), 3);
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.size(5), 1);
+        assert_eq!(sets.roots().count(), 3);
+        assert_eq!(sets.components(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+
+        let new = sets.push();
+        assert_eq!((new, sets.count()), (6, 4));
+    }
+
+    #[test]
+    fn kruskal() {
+        // minimum spanning tree of a square with one diagonal
+        let mut edges = [(0, 1, 1), (1, 2, 2), (2, 3, 1), (3, 0, 3), (0, 2, 2)];
+        edges.sort_by_key(|&(_, _, weight)| weight);
+
+        let mut sets = IndexUnionFind::new(4);
+        let total: u32 = edges
+            .iter()
+            .filter(|&&(a, b, _)| sets.union(a, b))
+            .map(|&(_, _, weight)| weight)
+            .sum();
+
+        assert_eq!(total, 4);
+        assert_eq!(sets.count(), 1);
+    }
+
+    #[test]
+    fn hashed() {
+        let mut sets: UnionFind<&str> = ["a", "b", "c"].into_iter().collect();
+        assert_eq!(sets.count(), 3);
+
+        assert!(sets.union(&"a", &"b"));
+        assert!(sets.union(&"d", &"e"));
+        assert!(!sets.union(&"b", &"a"));
+        assert_eq!((sets.len(), sets.count()), (5, 3));
+
+        assert!(sets.connected(&"a", &"b"));
+        assert!(!sets.connected(&"a", &"z"));
+        let root = sets.find(&"a").copied();
+        assert_eq!(sets.find(&"b").copied(), root);
+        assert_eq!(sets.find(&"z"), None);
+        assert_eq!(sets.size(&"e"), 2);
+        assert_eq!(sets.size(&"z"), 0);
+        assert_eq!(
+            sets.components(),
+            vec![vec![&"a", &"b"], vec![&"c"], vec![&"d", &"e"]]
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt;
+
+use anyhow::{bail, Result};
+
+use crate::answers::{self, Answers};
+use crate::{year_dir, Answer, Day, Failure, Unimplemented, Year};
+
+enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        answer: Answer,
+    },
+    Error(anyhow::Error),
+    /// No known answer to compare against.
+    Missing,
+    NoInput,
+    Unimplemented,
+}
+
+impl Status {
+    /// Describes what went wrong, if anything did.
+    fn failure(&self) -> Option<String> {
+        match self {
+            Status::Fail { expected, answer } => {
+                Some(format!("expected `{}', got `{}'", expected, answer))
+            }
+            Status::Error(err) => Some(format!("{:#}", err)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Error(_) => "ERROR",
+            Status::Missing => "missing",
+            Status::NoInput => "no input",
+            Status::Unimplemented => "-",
+        };
+
+        // forward to `str` so that width and alignment are respected
+        status.fmt(f)
+    }
+}
+
+/// Checks every implemented part of the selected days against the known answers, and prints a
+/// matrix of the results. Fails if any answer didn't match.
+pub fn verify(selected: &[(&Year, Vec<&Day>)]) -> Result<()> {
+    let mut failures = Vec::new();
+
+    println!("year  day  {:<9}part 2", "part 1");
+
+    for (y, days) in selected {
+        let answers = Answers::load(&year_dir(y.year).join("answers.toml"))?;
+
+        for d in days {
+            let statuses = [1, 2].map(|part| (part, verify_part(d, &answers, part)));
+
+            println!(
+                "{}  {:>3}  {:<9}{}",
+                y.year, d.day, statuses[0].1, statuses[1].1
+            );
+
+            for (part, status) in &statuses {
+                if let Some(failure) = status.failure() {
+                    failures.push(format!(
+                        "{} day {} part {}: {}",
+                        y.year, d.day, part, failure
+                    ));
+                }
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        println!();
+        for failure in &failures {
+            println!("{}", failure);
+        }
+
+        bail!("{} part(s) didn't match their known answer", failures.len());
+    }
+
+    Ok(())
+}
+
+fn verify_part(day: &Day, answers: &Answers, part: u8) -> Status {
+    let input = match day.input {
+        Some(input) => input,
+        None => return Status::NoInput,
+    };
+
+    let solved = match day.solve_timed(input, Some(part)) {
+        Ok(solved) => solved,
+        Err(failure) => return Status::Error(failure.into_error()),
+    };
+
+    let answer = match solved.parts.into_iter().next().map(|p| p.answer) {
+        Some(Ok(answer)) => answer,
+        // a `todo!()` is a part still being written, not a wrong one
+        None | Some(Err(Failure::Todo)) => return Status::Unimplemented,
+        Some(Err(Failure::Error(err))) if err.is::<Unimplemented>() => {
+            return Status::Unimplemented
+        }
+        Some(Err(failure)) => return Status::Error(failure.into_error()),
+    };
+
+    match answers.get(day.day, part) {
+        Ok(Some(expected)) if answers::matches(&answer, &expected) => Status::Pass,
+        Ok(Some(expected)) => Status::Fail { expected, answer },
+        Ok(None) => Status::Missing,
+        Err(err) => Status::Error(err),
+    }
+}
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+
+use crate::answers::{self, Answers};
+use crate::{workspace_root, year_dir, Answer, Day, Failure};
+
+/// How often the watched files are checked for changes.
+const POLL: Duration = Duration::from_millis(300);
+
+/// Last modification time of each watched file.
+type Snapshot = HashMap<PathBuf, SystemTime>;
+
+/// Runs `day` of `year` on its provided example and its real input, read from
+/// `aocYYYY/input/dayNN*.txt`, then again whenever one of those files or `answers.toml` changes.
+/// The example is checked against its answers in `answers.toml`, or by the day's own tests when
+/// there are none. Never returns unless the files can't be listed.
+///
+/// Changing the solution itself needs a rebuild, which `cargo watch` can take care of by
+/// restarting this command.
+pub(crate) fn watch(year: u16, day: &Day) -> Result<()> {
+    let dir = year_dir(year);
+    let mut snapshot = Snapshot::new();
+    let mut previous = HashMap::new();
+
+    loop {
+        let current = watched(&dir, day.day)?;
+        if current != snapshot {
+            // the first run isn't caused by a change
+            if !snapshot.is_empty() {
+                for path in changed(&snapshot, &current) {
+                    println!("--- `{}' changed", path.display());
+                }
+            }
+            snapshot = current;
+
+            if let Err(err) = run_once(year, day, &mut previous) {
+                println!("error: {:#}", err);
+            }
+            println!();
+        }
+
+        thread::sleep(POLL);
+    }
+}
+
+fn watched(dir: &Path, day: u8) -> Result<Snapshot> {
+    let input_dir = dir.join("input");
+    let prefix = format!("day{:02}", day);
+
+    let entries = fs::read_dir(&input_dir)
+        .with_context(|| format!("couldn't list `{}'", input_dir.display()))?;
+
+    let mut snapshot = Snapshot::new();
+    for path in entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(&prefix) && n.ends_with(".txt"))
+        })
+        .chain([dir.join("answers.toml")])
+    {
+        if let Ok(modified) = fs::metadata(&path).and_then(|m| m.modified()) {
+            snapshot.insert(path, modified);
+        }
+    }
+
+    Ok(snapshot)
+}
+
+/// Files added, modified or removed between two snapshots, in order.
+fn changed<'a>(before: &'a Snapshot, after: &'a Snapshot) -> Vec<&'a PathBuf> {
+    let mut changed: Vec<_> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path)
+        .chain(before.keys().filter(|path| !after.contains_key(*path)))
+        .collect();
+    changed.sort();
+
+    changed
+}
+
+/// Checks the provided example against its expected answers, then solves the real input, showing
+/// how each answer differs from the `previous` run.
+fn run_once(year: u16, day: &Day, previous: &mut HashMap<u8, String>) -> Result<()> {
+    let dir = year_dir(year);
+    let answers = Answers::load(&dir.join("answers.toml"))?;
+    let input_dir = dir.join("input");
+
+    let mut expected = Vec::new();
+    for part in [1, 2] {
+        expected.extend(answers.get_provided(day.day, part)?);
+    }
+    if expected.is_empty() {
+        // most days check their example in their `dayNN::tests`
+        example_tests(year, day.day)?;
+    } else if let Some(example) = read(&input_dir.join(format!("day{:02}_provided.txt", day.day)))?
+    {
+        for (part, answer) in solve(day, &example) {
+            let status = match (answer, answers.get_provided(day.day, part)?) {
+                (Ok(answer), Some(expected)) if answers::matches(&answer, &expected) => {
+                    format!("{} ok", answer)
+                }
+                (Ok(answer), Some(expected)) => format!("{} FAIL, expected `{}'", answer, expected),
+                (Ok(answer), None) => format!("{}, no expected answer", answer),
+                (Err(failure), _) => format!("{}: {}", failure.status(), failure),
+            };
+            println!("example part {}: {}", part, status);
+        }
+    }
+
+    let input = match read(&input_dir.join(format!("day{:02}.txt", day.day)))? {
+        Some(input) => input,
+        None => {
+            println!("no input yet, `aoc fetch' downloads it");
+            return Ok(());
+        }
+    };
+
+    for (part, answer) in solve(day, &input) {
+        match answer {
+            Ok(answer) => {
+                let known = match answers.get(day.day, part)? {
+                    Some(expected) if answers::matches(&answer, &expected) => ", known answer",
+                    Some(_) => ", NOT the known answer",
+                    None => "",
+                };
+                let answer = answer.to_string();
+                println!(
+                    "part {}: {}{}{}",
+                    part,
+                    answer,
+                    diff(previous.get(&part).map(String::as_str), &answer),
+                    known
+                );
+                previous.insert(part, answer);
+            }
+            Err(failure) => println!("part {}: {}: {}", part, failure.status(), failure),
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs the `dayNN::tests` of the year crate with `cargo test`, printing their output only if
+/// they fail.
+fn example_tests(year: u16, day: u8) -> Result<()> {
+    let filter = format!("day{:02}::tests::", day);
+    let output = Command::new("cargo")
+        .current_dir(workspace_root())
+        .args([
+            "test",
+            "--quiet",
+            "--lib",
+            "-p",
+            &format!("aoc{}", year),
+            "--",
+        ])
+        .arg(&filter)
+        .output()
+        .context("couldn't run `cargo test'")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match test_summary(&stdout) {
+        Some(summary) if output.status.success() => println!("example tests: {}", summary),
+        _ => {
+            println!("example tests: FAIL");
+            print!("{}{}", stdout, String::from_utf8_lossy(&output.stderr));
+        }
+    }
+
+    Ok(())
+}
+
+/// The `test result` line printed by the test harness, without its timing.
+fn test_summary(stdout: &str) -> Option<&str> {
+    let summary = stdout
+        .lines()
+        .find_map(|l| l.strip_prefix("test result: "))?;
+
+    Some(summary.split("; finished").next().unwrap_or(summary))
+}
+
+/// Solves each part of `day`, a failure to parse counting for both.
+fn solve(day: &Day, input: &str) -> Vec<(u8, Result<Answer, Failure>)> {
+    match day.solve_timed(input, None) {
+        Ok(solved) => solved
+            .parts
+            .into_iter()
+            .map(|p| (p.part, p.answer))
+            .collect(),
+        Err(failure) => vec![(1, Err(failure))],
+    }
+}
+
+/// Reads `path`, an empty or missing file meaning the input isn't there yet.
+fn read(path: &Path) -> Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(content) if content.trim().is_empty() => Ok(None),
+        Ok(content) => Ok(Some(content)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("couldn't read `{}'", path.display())),
+    }
+}
+
+/// How `answer` compares to the one of the previous run, if any.
+fn diff(previous: Option<&str>, answer: &str) -> String {
+    match previous {
+        None => String::new(),
+        Some(previous) if previous == answer => " (unchanged)".to_string(),
+        Some(previous) if previous.contains('\n') || answer.contains('\n') => {
+            format!(" (was:\n{})", previous)
+        }
+        Some(previous) => format!(" (was {})", previous),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs() {
+        assert_eq!(diff(None, "12"), "");
+        assert_eq!(diff(Some("12"), "12"), " (unchanged)");
+        assert_eq!(diff(Some("12"), "15"), " (was 12)");
+        assert_eq!(diff(Some("#.\n.#"), "#.\n##"), " (was:\n#.\n.#)");
+    }
+
+    #[test]
+    fn summaries() {
+        let stdout = "\nrunning 2 tests\n..\ntest result: ok. 2 passed; 0 failed; 0 ignored; \
+                      0 measured; 38 filtered out; finished in 0.01s\n\n";
+        assert_eq!(
+            test_summary(stdout),
+            Some("ok. 2 passed; 0 failed; 0 ignored; 0 measured; 38 filtered out")
+        );
+        assert_eq!(test_summary("error: could not compile"), None);
+    }
+
+    #[test]
+    fn changed_files() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+
+        let before = Snapshot::from([
+            (PathBuf::from("day01.txt"), t0),
+            (PathBuf::from("day01_provided.txt"), t0),
+            (PathBuf::from("day01_other.txt"), t0),
+        ]);
+        let after = Snapshot::from([
+            (PathBuf::from("day01.txt"), t0),
+            (PathBuf::from("day01_provided.txt"), t1),
+            (PathBuf::from("answers.toml"), t0),
+        ]);
+
+        assert_eq!(
+            changed(&before, &after),
+            vec![
+                &PathBuf::from("answers.toml"),
+                &PathBuf::from("day01_other.txt"),
+                &PathBuf::from("day01_provided.txt"),
+            ]
+        );
+    }
+}
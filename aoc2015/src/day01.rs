@@ -1,39 +1,43 @@
-use std::fmt::Write;
-
 use anyhow::{anyhow, bail, Result};
 
-const INPUT: &str = include_str!("../input/day01.txt");
+use aoc::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i64>;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .trim_end()
+            .chars()
+            .map(|c| match c {
+                '(' => Ok(1),
+                ')' => Ok(-1),
+                _ => bail!("unidentified character in input: {}", c),
+            })
+            .collect()
+    }
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn part1(moves: &Self::Input) -> Result<Answer> {
+        Ok(part1(moves).into())
+    }
 
-    Ok(res)
+    fn part2(moves: &Self::Input) -> Result<Answer> {
+        Ok(part2(moves)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<i64> {
-    Ok(input
-        .chars()
-        .filter_map(|c| match c {
-            '(' => Some(1),
-            ')' => Some(-1),
-            _ => None,
-        })
-        .sum())
+fn part1(moves: &[i64]) -> i64 {
+    moves.iter().sum()
 }
 
-fn part2(input: &str) -> Result<usize> {
+fn part2(moves: &[i64]) -> Result<usize> {
     let mut sum = 0;
     let mut res = 0;
 
-    for (i, c) in input.chars().enumerate() {
-        match c {
-            '(' => sum += 1,
-            ')' => sum -= 1,
-            _ => bail!("unidentified character in input: {}", c),
-        };
+    for (i, mv) in moves.iter().enumerate() {
+        sum += mv;
 
         if sum < 0 {
             res = i + 1;
@@ -53,30 +57,20 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1("(())").unwrap(), 0);
-        assert_eq!(part1("()()").unwrap(), 0);
-        assert_eq!(part1("(((").unwrap(), 3);
-        assert_eq!(part1("(()(()(").unwrap(), 3);
-        assert_eq!(part1("))(((((").unwrap(), 3);
-        assert_eq!(part1("())").unwrap(), -1);
-        assert_eq!(part1("))(").unwrap(), -1);
-        assert_eq!(part1(")))").unwrap(), -3);
-        assert_eq!(part1(")())())").unwrap(), -3);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 74);
+        assert_eq!(part1(&Day01::parse("(())").unwrap()), 0);
+        assert_eq!(part1(&Day01::parse("()()").unwrap()), 0);
+        assert_eq!(part1(&Day01::parse("(((").unwrap()), 3);
+        assert_eq!(part1(&Day01::parse("(()(()(").unwrap()), 3);
+        assert_eq!(part1(&Day01::parse("))(((((").unwrap()), 3);
+        assert_eq!(part1(&Day01::parse("())").unwrap()), -1);
+        assert_eq!(part1(&Day01::parse("))(").unwrap()), -1);
+        assert_eq!(part1(&Day01::parse(")))").unwrap()), -3);
+        assert_eq!(part1(&Day01::parse(")())())").unwrap()), -3);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(")").unwrap(), 1);
-        assert_eq!(part2("()())").unwrap(), 5);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 1795);
+        assert_eq!(part2(&Day01::parse(")").unwrap()).unwrap(), 1);
+        assert_eq!(part2(&Day01::parse("()())").unwrap()).unwrap(), 5);
     }
 }
@@ -1,19 +1,25 @@
-use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day02.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day02;
 
-    let presents: Vec<Present> = INPUT.lines().map(str::parse).collect::<Result<_>>()?;
+impl Solution for Day02 {
+    type Input = Vec<Present>;
 
-    writeln!(res, "part 1: {}", part1(&presents))?;
-    writeln!(res, "part 2: {}", part2(&presents))?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(presents: &Self::Input) -> Result<Answer> {
+        Ok(part1(presents).into())
+    }
 
-    Ok(res)
+    fn part2(presents: &Self::Input) -> Result<Answer> {
+        Ok(part2(presents).into())
+    }
 }
 
 fn wrapping_paper(present: &Present) -> u64 {
@@ -52,7 +58,7 @@ fn part2(presents: &[Present]) -> u64 {
     presents.iter().map(ribbon_needed).sum()
 }
 
-struct Present {
+pub struct Present {
     length: u64,
     width: u64,
     height: u64,
@@ -97,31 +103,9 @@ mod tests {
         assert_eq!(wrapping_paper(&"1x1x10".parse().unwrap()), 43);
     }
 
-    #[test]
-    fn part1_real() {
-        let presents: Vec<Present> = INPUT
-            .lines()
-            .map(str::parse)
-            .collect::<Result<_>>()
-            .unwrap();
-
-        assert_eq!(part1(&presents), 1598415);
-    }
-
     #[test]
     fn part2_provided() {
         assert_eq!(ribbon_needed(&"2x3x4".parse().unwrap()), 34);
         assert_eq!(ribbon_needed(&"1x1x10".parse().unwrap()), 14);
     }
-
-    #[test]
-    fn part2_real() {
-        let presents: Vec<Present> = INPUT
-            .lines()
-            .map(str::parse)
-            .collect::<Result<_>>()
-            .unwrap();
-
-        assert_eq!(part2(&presents), 3812909);
-    }
 }
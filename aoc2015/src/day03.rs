@@ -1,20 +1,38 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::{bail, Result};
 
-const INPUT: &str = include_str!("../input/day03.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day03;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day03 {
+    type Input = Vec<(i64, i64)>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .trim_end()
+            .chars()
+            .map(|c| match c {
+                '>' => Ok((1, 0)),
+                '<' => Ok((-1, 0)),
+                '^' => Ok((0, -1)),
+                'v' => Ok((0, 1)),
+                _ => bail!("unidentified move: `{}`", c),
+            })
+            .collect()
+    }
+
+    fn part1(moves: &Self::Input) -> Result<Answer> {
+        Ok(part1(moves).into())
+    }
+
+    fn part2(moves: &Self::Input) -> Result<Answer> {
+        Ok(part2(moves).into())
+    }
 }
 
-fn part1(input: &str) -> Result<usize> {
+fn part1(moves: &[(i64, i64)]) -> usize {
     let mut houses = HashSet::new();
 
     let mut x = 0;
@@ -22,22 +40,17 @@ fn part1(input: &str) -> Result<usize> {
 
     houses.insert((x, y));
 
-    for c in input.trim_end().chars() {
-        match c {
-            '>' => x += 1,
-            '<' => x -= 1,
-            '^' => y -= 1,
-            'v' => y += 1,
-            _ => bail!("unidentified move: `{}`", c),
-        }
+    for (dx, dy) in moves {
+        x += dx;
+        y += dy;
 
         houses.insert((x, y));
     }
 
-    Ok(houses.len())
+    houses.len()
 }
 
-fn part2(input: &str) -> Result<usize> {
+fn part2(moves: &[(i64, i64)]) -> usize {
     let mut houses = HashSet::new();
 
     let mut santa_x = 0;
@@ -48,31 +61,21 @@ fn part2(input: &str) -> Result<usize> {
 
     houses.insert((0, 0));
 
-    for (i, c) in input.trim_end().chars().enumerate() {
+    for (i, (dx, dy)) in moves.iter().enumerate() {
         if i % 2 == 0 {
-            match c {
-                '>' => santa_x += 1,
-                '<' => santa_x -= 1,
-                '^' => santa_y -= 1,
-                'v' => santa_y += 1,
-                _ => bail!("unidentified move: `{}`", c),
-            }
+            santa_x += dx;
+            santa_y += dy;
 
             houses.insert((santa_x, santa_y));
         } else {
-            match c {
-                '>' => robot_x += 1,
-                '<' => robot_x -= 1,
-                '^' => robot_y -= 1,
-                'v' => robot_y += 1,
-                _ => bail!("unidentified move: `{}`", c),
-            }
+            robot_x += dx;
+            robot_y += dy;
 
             houses.insert((robot_x, robot_y));
         }
     }
 
-    Ok(houses.len())
+    houses.len()
 }
 
 #[cfg(test)]
@@ -81,20 +84,15 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(">").unwrap(), 2);
-        assert_eq!(part1("^>v<").unwrap(), 4);
-        assert_eq!(part1("^v^v^v^v^v").unwrap(), 2);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 2565);
+        assert_eq!(part1(&Day03::parse(">").unwrap()), 2);
+        assert_eq!(part1(&Day03::parse("^>v<").unwrap()), 4);
+        assert_eq!(part1(&Day03::parse("^v^v^v^v^v").unwrap()), 2);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2("^v").unwrap(), 3);
-        assert_eq!(part2("^>v<").unwrap(), 3);
-        assert_eq!(part2("^v^v^v^v^v").unwrap(), 11);
+        assert_eq!(part2(&Day03::parse("^v").unwrap()), 3);
+        assert_eq!(part2(&Day03::parse("^>v<").unwrap()), 3);
+        assert_eq!(part2(&Day03::parse("^v^v^v^v^v").unwrap()), 11);
     }
 }
@@ -1,28 +1,34 @@
-use std::fmt::Write;
-
 use md5::{Digest, Md5};
 
 use anyhow::{anyhow, Result};
 
-const INPUT: &str = include_str!("../input/day04.txt");
+use aoc::{Answer, Solution};
+
+pub struct Day04;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+impl Solution for Day04 {
+    type Input = String;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim_end().to_string())
+    }
 
-    Ok(res)
+    fn part1(key: &Self::Input) -> Result<Answer> {
+        Ok(part1(key)?.into())
+    }
+
+    fn part2(key: &Self::Input) -> Result<Answer> {
+        Ok(part2(key)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let input = input.trim_end();
-    let mut content = String::from(input);
+fn part1(key: &str) -> Result<u64> {
+    let mut content = String::from(key);
 
     let mut hasher = Md5::new();
 
     for i in 0.. {
-        content.truncate(input.len());
+        content.truncate(key.len());
         content.push_str(&i.to_string());
 
         hasher.input(&content);
@@ -35,14 +41,13 @@ fn part1(input: &str) -> Result<u64> {
     Err(anyhow!("couldn't find a suitable number"))
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let input = input.trim_end();
-    let mut content = String::from(input);
+fn part2(key: &str) -> Result<u64> {
+    let mut content = String::from(key);
 
     let mut hasher = Md5::new();
 
     for i in 0.. {
-        content.truncate(input.len());
+        content.truncate(key.len());
         content.push_str(&i.to_string());
 
         hasher.input(&content);
@@ -65,16 +70,4 @@ mod tests {
         assert_eq!(part1("abcdef").unwrap(), 609043);
         assert_eq!(part1("pqrstuv").unwrap(), 1048970);
     }
-
-    #[test]
-    #[ignore] // takes too long!
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 282749);
-    }
-
-    #[test]
-    #[ignore] // takes too long!
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 9962624);
-    }
 }
@@ -1,22 +1,31 @@
-use std::fmt::Write;
-
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day05.txt");
+use aoc::{Answer, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<String>;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input
+            .lines()
+            .map(|line| line.trim_end().to_string())
+            .collect())
+    }
 
-    writeln!(res, "part 1: {}", part1(INPUT))?;
-    writeln!(res, "part 2: {}", part2(INPUT))?;
+    fn part1(strings: &Self::Input) -> Result<Answer> {
+        Ok(part1(strings).into())
+    }
 
-    Ok(res)
+    fn part2(strings: &Self::Input) -> Result<Answer> {
+        Ok(part2(strings).into())
+    }
 }
 
-fn part1(input: &str) -> usize {
-    input
-        .lines()
-        .map(str::trim_end)
+fn part1(strings: &[String]) -> usize {
+    strings
+        .iter()
         .filter(|line| {
             let mut vowel_count = 0;
             for c in line.chars() {
@@ -48,10 +57,9 @@ fn part1(input: &str) -> usize {
         .count()
 }
 
-fn part2(input: &str) -> usize {
-    input
-        .lines()
-        .map(str::trim_end)
+fn part2(strings: &[String]) -> usize {
+    strings
+        .iter()
         .filter(|line| {
             for i in 0..(line.chars().count() - 3) {
                 let seq = &line[i..(i + 2)];
@@ -81,28 +89,18 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1("ugknbfddgicrmopn"), 1);
-        assert_eq!(part1("aaa"), 1);
-        assert_eq!(part1("jchzalrnumimnmhp"), 0);
-        assert_eq!(part1("haegwjzuvuyypxyu"), 0);
-        assert_eq!(part1("dvszwmarrgswjxmb"), 0);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT), 258);
+        assert_eq!(part1(&["ugknbfddgicrmopn".to_string()]), 1);
+        assert_eq!(part1(&["aaa".to_string()]), 1);
+        assert_eq!(part1(&["jchzalrnumimnmhp".to_string()]), 0);
+        assert_eq!(part1(&["haegwjzuvuyypxyu".to_string()]), 0);
+        assert_eq!(part1(&["dvszwmarrgswjxmb".to_string()]), 0);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2("qjhvhtzxzqqjkmpb"), 1);
-        assert_eq!(part2("xxyxx"), 1);
-        assert_eq!(part2("uurcxstgmygtbstg"), 0);
-        assert_eq!(part2("ieodomkazucvgmuy"), 0);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT), 53);
+        assert_eq!(part2(&["qjhvhtzxzqqjkmpb".to_string()]), 1);
+        assert_eq!(part2(&["xxyxx".to_string()]), 1);
+        assert_eq!(part2(&["uurcxstgmygtbstg".to_string()]), 0);
+        assert_eq!(part2(&["ieodomkazucvgmuy".to_string()]), 0);
     }
 }
@@ -1,29 +1,35 @@
-use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day06.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day06;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day06 {
+    type Input = Vec<Instruction>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                line.trim_end()
+                    .parse()
+                    .with_context(|| format!("couldn't parse instruction: {}", line))
+            })
+            .collect()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let instructions = input
-        .lines()
-        .map(|line| {
-            line.trim_end()
-                .parse()
-                .context("couldn't parse instruction: {}")
-        })
-        .collect::<Result<Vec<Instruction>>>()?;
+    fn part1(instructions: &Self::Input) -> Result<Answer> {
+        Ok(part1(instructions).into())
+    }
 
+    fn part2(instructions: &Self::Input) -> Result<Answer> {
+        Ok(part2(instructions).into())
+    }
+}
+
+fn part1(instructions: &[Instruction]) -> usize {
     let mut grid: Vec<Vec<bool>> = vec![vec![false; 1000]; 1000];
 
     for inst in instructions {
@@ -46,23 +52,13 @@ fn part1(input: &str) -> Result<usize> {
         }
     }
 
-    Ok(grid
-        .iter()
+    grid.iter()
         .flat_map(|line| line.iter())
         .filter(|v| **v)
-        .count())
+        .count()
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let instructions = input
-        .lines()
-        .map(|line| {
-            line.trim_end()
-                .parse()
-                .context("couldn't parse instruction: {}")
-        })
-        .collect::<Result<Vec<Instruction>>>()?;
-
+fn part2(instructions: &[Instruction]) -> u64 {
     let mut grid: Vec<Vec<u64>> = vec![vec![0; 1000]; 1000];
 
     for inst in instructions {
@@ -85,7 +81,7 @@ fn part2(input: &str) -> Result<u64> {
         }
     }
 
-    Ok(grid.iter().flat_map(|line| line.iter()).sum())
+    grid.iter().flat_map(|line| line.iter()).sum()
 }
 
 enum Action {
@@ -94,13 +90,13 @@ enum Action {
     Toggle,
 }
 
-struct Instruction {
+pub struct Instruction {
     action: Action,
     horizontal: (usize, usize),
     vertical: (usize, usize),
 }
 
-impl IntoIterator for Instruction {
+impl IntoIterator for &Instruction {
     type Item = (usize, usize);
     type IntoIter = Box<dyn Iterator<Item = Self::Item>>;
 
@@ -187,24 +183,26 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1("turn on 0,0 through 999,999").unwrap(), 1_000_000);
-        assert_eq!(part1("toggle 0,0 through 999,0").unwrap(), 1_000);
-        assert_eq!(part1("turn off 499,499 through 500,500").unwrap(), 0);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 543903);
+        assert_eq!(
+            part1(&Day06::parse("turn on 0,0 through 999,999").unwrap()),
+            1_000_000
+        );
+        assert_eq!(
+            part1(&Day06::parse("toggle 0,0 through 999,0").unwrap()),
+            1_000
+        );
+        assert_eq!(
+            part1(&Day06::parse("turn off 499,499 through 500,500").unwrap()),
+            0
+        );
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2("turn on 0,0 through 0,0").unwrap(), 1);
-        assert_eq!(part2("toggle 0,0 through 999,999").unwrap(), 2_000_000);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 14687245);
+        assert_eq!(part2(&Day06::parse("turn on 0,0 through 0,0").unwrap()), 1);
+        assert_eq!(
+            part2(&Day06::parse("toggle 0,0 through 999,999").unwrap()),
+            2_000_000
+        );
     }
 }
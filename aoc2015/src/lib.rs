@@ -6,3 +6,5 @@ pub mod day03;
 pub mod day04;
 pub mod day05;
 pub mod day06;
+
+aoc::year!();
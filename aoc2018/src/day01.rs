@@ -1,38 +1,42 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day01.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day01;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day01 {
+    type Input = Vec<i32>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(|line| Ok(line.parse()?)).collect()
+    }
+
+    fn part1(changes: &Self::Input) -> Result<Answer> {
+        Ok(part1(changes).into())
+    }
+
+    fn part2(changes: &Self::Input) -> Result<Answer> {
+        Ok(part2(changes).into())
+    }
 }
 
-fn part1(input: &str) -> Result<i32> {
-    let freq = input
-        .lines()
-        .map(|line| line.parse::<i32>().unwrap())
-        .sum::<i32>();
-    Ok(freq)
+fn part1(changes: &[i32]) -> i32 {
+    changes.iter().sum()
 }
 
-fn part2(input: &str) -> Result<i32> {
+fn part2(changes: &[i32]) -> i32 {
     let mut freqs = HashSet::new();
     let mut freq = 0;
     loop {
-        for line in input.lines() {
+        for change in changes {
             if freqs.contains(&freq) {
-                return Ok(freq);
+                return freq;
             } else {
                 freqs.insert(freq);
             }
-            freq += line.parse::<i32>()?;
+            freq += change;
         }
     }
 }
@@ -64,15 +68,10 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 3);
-        assert_eq!(part1(PROVIDED2).unwrap(), 3);
-        assert_eq!(part1(PROVIDED3).unwrap(), 0);
-        assert_eq!(part1(PROVIDED4).unwrap(), -6);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 427);
+        assert_eq!(part1(&Day01::parse(PROVIDED1).unwrap()), 3);
+        assert_eq!(part1(&Day01::parse(PROVIDED2).unwrap()), 3);
+        assert_eq!(part1(&Day01::parse(PROVIDED3).unwrap()), 0);
+        assert_eq!(part1(&Day01::parse(PROVIDED4).unwrap()), -6);
     }
 
     const PROVIDED5: &str = "+1
@@ -102,15 +101,10 @@ mod tests {
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED1).unwrap(), 2);
-        assert_eq!(part2(PROVIDED5).unwrap(), 0);
-        assert_eq!(part2(PROVIDED6).unwrap(), 10);
-        assert_eq!(part2(PROVIDED7).unwrap(), 5);
-        assert_eq!(part2(PROVIDED8).unwrap(), 14);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 341);
+        assert_eq!(part2(&Day01::parse(PROVIDED1).unwrap()), 2);
+        assert_eq!(part2(&Day01::parse(PROVIDED5).unwrap()), 0);
+        assert_eq!(part2(&Day01::parse(PROVIDED6).unwrap()), 10);
+        assert_eq!(part2(&Day01::parse(PROVIDED7).unwrap()), 5);
+        assert_eq!(part2(&Day01::parse(PROVIDED8).unwrap()), 14);
     }
 }
@@ -1,26 +1,34 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day02.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day02;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day02 {
+    type Input = Vec<String>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(ids: &Self::Input) -> Result<Answer> {
+        Ok(part1(ids).into())
+    }
+
+    fn part2(ids: &Self::Input) -> Result<Answer> {
+        Ok(part2(ids).into())
+    }
 }
 
-fn part1(input: &str) -> Result<u32> {
+fn part1(ids: &[String]) -> u32 {
     let mut twice = 0;
     let mut thrice = 0;
 
-    for line in input.lines() {
+    for id in ids {
         let mut seen: HashMap<char, u32> = HashMap::new();
-        for c in line.chars() {
+        for c in id.chars() {
             *seen.entry(c).or_default() += 1;
         }
 
@@ -33,27 +41,25 @@ fn part1(input: &str) -> Result<u32> {
         }
     }
 
-    Ok(twice * thrice)
+    twice * thrice
 }
 
-fn part2(input: &str) -> Result<String> {
-    let lines = input.lines().collect::<Vec<&str>>();
-
-    for i in 0..lines.len() {
-        for j in (i + 1)..lines.len() {
-            let different = lines[i]
+fn part2(ids: &[String]) -> String {
+    for i in 0..ids.len() {
+        for j in (i + 1)..ids.len() {
+            let different = ids[i]
                 .chars()
-                .zip(lines[j].chars())
+                .zip(ids[j].chars())
                 .filter(|tuple| tuple.0 != tuple.1)
                 .count();
 
             if different == 1 {
-                return Ok(common_letters(lines[i], lines[j]));
+                return common_letters(&ids[i], &ids[j]);
             }
         }
     }
 
-    Ok("".into())
+    "".into()
 }
 
 fn common_letters(a: &str, b: &str) -> String {
@@ -81,12 +87,7 @@ abcdee
 ababab
 ";
 
-        assert_eq!(part1(input).unwrap(), 12);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 5750);
+        assert_eq!(part1(&Day02::parse(input).unwrap()), 12);
     }
 
     #[test]
@@ -100,11 +101,6 @@ axcye
 wvxyz
 ";
 
-        assert_eq!(part2(input).unwrap(), "fgij");
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), "tzyvunogzariwkpcbdewmjhxi");
+        assert_eq!(part2(&Day02::parse(input).unwrap()), "fgij");
     }
 }
@@ -1,22 +1,36 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day03.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day03;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day03 {
+    type Input = Vec<Claim>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                line.parse()
+                    .with_context(|| format!("couldn't parse line: `{}`", line))
+            })
+            .collect()
+    }
+
+    fn part1(claims: &Self::Input) -> Result<Answer> {
+        Ok(part1(claims).into())
+    }
+
+    fn part2(claims: &Self::Input) -> Result<Answer> {
+        Ok(part2(claims).into())
+    }
 }
 
-struct Claim {
+pub struct Claim {
     x: usize,
     y: usize,
     width: usize,
@@ -66,15 +80,11 @@ impl FromStr for Claim {
     }
 }
 
-fn part1(input: &str) -> Result<u64> {
+fn part1(claims: &[Claim]) -> u64 {
     let mut res = 0;
     let mut map: HashMap<(usize, usize), u64> = HashMap::new();
 
-    for line in input.lines() {
-        let claim: Claim = line
-            .parse()
-            .with_context(|| format!("couldn't parse line: `{}`", line))?;
-
+    for claim in claims {
         for i in 0..claim.width {
             for j in 0..claim.height {
                 let x = claim.x + i;
@@ -92,17 +102,14 @@ fn part1(input: &str) -> Result<u64> {
         }
     }
 
-    Ok(res)
+    res
 }
 
-fn part2(input: &str) -> Result<usize> {
+fn part2(claims: &[Claim]) -> usize {
     let mut map: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
     let mut set = HashSet::new();
 
-    for line in input.lines() {
-        let claim: Claim = line
-            .parse()
-            .with_context(|| format!("couldn't parse line: `{}`", line))?;
+    for claim in claims {
         set.insert(claim.id);
 
         for i in 0..claim.width {
@@ -125,7 +132,7 @@ fn part2(input: &str) -> Result<usize> {
     }
 
     assert!(!set.is_empty());
-    Ok(set.into_iter().next().unwrap())
+    set.into_iter().next().unwrap()
 }
 
 #[cfg(test)]
@@ -139,21 +146,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 4);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 114946);
+        assert_eq!(part1(&Day03::parse(PROVIDED).unwrap()), 4);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 3);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 877);
+        assert_eq!(part2(&Day03::parse(PROVIDED).unwrap()), 3);
     }
 }
@@ -1,23 +1,25 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{anyhow, bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day04.txt");
+use aoc::{Answer, Solution};
 
-fn sorted_lines(input: &str) -> String {
-    let mut lines: Vec<_> = input.lines().collect();
-    lines.sort_unstable();
-    lines.join("\n")
-}
+pub struct Day04;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+impl Solution for Day04 {
+    type Input = Vec<LogEntry>;
 
-    writeln!(res, "part 1: {}", part1(&sorted_lines(INPUT))?)?;
+    /// The log isn't in chronological order, but its timestamps sort like strings.
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines: Vec<_> = input.lines().collect();
+        lines.sort_unstable();
+        lines.into_iter().map(str::parse).collect()
+    }
 
-    Ok(res)
+    fn part1(log: &Self::Input) -> Result<Answer> {
+        Ok(part1(log)?.into())
+    }
 }
 
 #[derive(Debug)]
@@ -94,7 +96,7 @@ impl FromStr for Date {
 }
 
 #[derive(Debug)]
-struct LogEntry {
+pub struct LogEntry {
     date: Date,
     event: Event,
 }
@@ -119,13 +121,11 @@ impl FromStr for LogEntry {
     }
 }
 
-fn part1(input: &str) -> Result<u64> {
+fn part1(log: &[LogEntry]) -> Result<u64> {
     let mut guard_id = None;
-    let mut map: HashMap<u64, Vec<LogEntry>> = HashMap::new();
-
-    for line in input.lines() {
-        let log_entry: LogEntry = line.parse()?;
+    let mut map: HashMap<u64, Vec<&LogEntry>> = HashMap::new();
 
+    for log_entry in log {
         if let Event::ShiftChange(id) = log_entry.event {
             guard_id = Some(id);
         }
@@ -207,11 +207,6 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 240);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(&sorted_lines(INPUT)).unwrap(), 142515);
+        assert_eq!(part1(&Day04::parse(PROVIDED).unwrap()).unwrap(), 240);
     }
 }
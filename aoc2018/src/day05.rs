@@ -1,17 +1,25 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day05.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day05;
 
-    writeln!(res, "part 1: {}", part1(INPUT))?;
-    writeln!(res, "part 2: {}", part2(INPUT))?;
+impl Solution for Day05 {
+    type Input = String;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(polymer: &Self::Input) -> Result<Answer> {
+        Ok(part1(polymer).into())
+    }
+
+    fn part2(polymer: &Self::Input) -> Result<Answer> {
+        Ok(part2(polymer).into())
+    }
 }
 
 fn remove_type(input: &str, c: char) -> String {
@@ -83,18 +91,8 @@ mod tests {
         assert_eq!(part1(PROVIDED), 10);
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT), 10638);
-    }
-
     #[test]
     fn part2_provided() {
         assert_eq!(part2(PROVIDED), 4);
     }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT), 4944);
-    }
 }
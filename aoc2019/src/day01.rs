@@ -1,28 +1,31 @@
-use std::fmt::Write;
-
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day01.txt");
+use aoc::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u64>;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(|line| Ok(line.parse()?)).collect()
+    }
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn part1(modules: &Self::Input) -> Result<Answer> {
+        Ok(part1(modules).into())
+    }
 
-    Ok(res)
+    fn part2(modules: &Self::Input) -> Result<Answer> {
+        Ok(part2(modules).into())
+    }
 }
 
 fn fuel_needed(module_weight: u64) -> u64 {
     (module_weight / 3).saturating_sub(2)
 }
 
-fn part1(input: &str) -> Result<u64> {
-    input
-        .lines()
-        .map(str::parse)
-        .map(|w| w.map(fuel_needed).map_err(anyhow::Error::new))
-        .sum()
+fn part1(modules: &[u64]) -> u64 {
+    modules.iter().copied().map(fuel_needed).sum()
 }
 
 fn cumulated_fuel_needed(module_weight: u64) -> u64 {
@@ -37,12 +40,8 @@ fn cumulated_fuel_needed(module_weight: u64) -> u64 {
     total_fuel
 }
 
-fn part2(input: &str) -> Result<u64> {
-    input
-        .lines()
-        .map(str::parse)
-        .map(|w| w.map(cumulated_fuel_needed).map_err(anyhow::Error::new))
-        .sum()
+fn part2(modules: &[u64]) -> u64 {
+    modules.iter().copied().map(cumulated_fuel_needed).sum()
 }
 
 #[cfg(test)]
@@ -57,20 +56,10 @@ mod tests {
         assert_eq!(fuel_needed(100756), 33583);
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 3268951);
-    }
-
     #[test]
     fn part2_provided() {
         assert_eq!(cumulated_fuel_needed(14), 2);
         assert_eq!(cumulated_fuel_needed(1969), 966);
         assert_eq!(cumulated_fuel_needed(100756), 50346);
     }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 4900568);
-    }
 }
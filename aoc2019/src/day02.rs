@@ -1,21 +1,27 @@
-use std::fmt::Write;
-
 use anyhow::{anyhow, bail, Context, Result};
 
+use aoc::{Answer, Solution};
+
 use crate::intcode::{parse_memory, Intcode};
 
-const INPUT: &str = include_str!("../input/day02.txt");
 const PART2_EXPECTED: i64 = 19_690_720;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<i64>;
 
-    let memory = parse_memory(INPUT)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_memory(input)
+    }
 
-    writeln!(res, "part 1: {}", part1(memory.clone())?)?;
-    writeln!(res, "part 2: {}", part2(&memory, PART2_EXPECTED)?)?;
+    fn part1(memory: &Self::Input) -> Result<Answer> {
+        Ok(part1(memory.clone())?.into())
+    }
 
-    Ok(res)
+    fn part2(memory: &Self::Input) -> Result<Answer> {
+        Ok(part2(memory, PART2_EXPECTED)?.into())
+    }
 }
 
 fn part1(mut input: Vec<i64>) -> Result<i64> {
@@ -84,16 +90,4 @@ mod tests {
         intcode.run().unwrap();
         assert_eq!(&intcode.memory, &[30, 1, 1, 4, 2, 5, 6, 0, 99]);
     }
-
-    #[test]
-    fn part1_real() {
-        let memory = parse_memory(INPUT).unwrap();
-        assert_eq!(part1(memory).unwrap(), 6568671);
-    }
-
-    #[test]
-    fn part2_real() {
-        let memory = parse_memory(INPUT).unwrap();
-        assert_eq!(part2(&memory, PART2_EXPECTED).unwrap(), 3951);
-    }
 }
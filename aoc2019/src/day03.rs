@@ -1,20 +1,26 @@
 use std::cmp::{max, min};
-use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day03.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day03;
 
-    let (first, second) = parse_wires(INPUT)?;
+impl Solution for Day03 {
+    type Input = (Wire, Wire);
 
-    writeln!(res, "part 1: {}", part1(&first, &second)?)?;
-    writeln!(res, "part 2: {}", part2(&first, &second)?)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_wires(input)
+    }
+
+    fn part1((first, second): &Self::Input) -> Result<Answer> {
+        Ok(part1(first, second)?.into())
+    }
 
-    Ok(res)
+    fn part2((first, second): &Self::Input) -> Result<Answer> {
+        Ok(part2(first, second)?.into())
+    }
 }
 
 fn manhattan_distance(a: &Point, b: &Point) -> u64 {
@@ -81,7 +87,7 @@ fn parse_wires(input: &str) -> Result<(Wire, Wire)> {
 }
 
 #[derive(Debug)]
-struct Wire(Vec<Segment>);
+pub struct Wire(Vec<Segment>);
 
 impl FromStr for Wire {
     type Err = anyhow::Error;
@@ -234,12 +240,6 @@ U98,R91,D20,R16,D67,R40,U7,R15,U6,R7
         assert_eq!(part1(&first, &second).unwrap(), 135);
     }
 
-    #[test]
-    fn part1_real() {
-        let (first, second) = parse_wires(INPUT).unwrap();
-        assert_eq!(part1(&first, &second).unwrap(), 273);
-    }
-
     #[test]
     fn part2_provided() {
         let (first, second) = parse_wires(PROVIDED1).unwrap();
@@ -249,10 +249,4 @@ U98,R91,D20,R16,D67,R40,U7,R15,U6,R7
         let (first, second) = parse_wires(PROVIDED3).unwrap();
         assert_eq!(part2(&first, &second).unwrap(), 410);
     }
-
-    #[test]
-    fn part2_real() {
-        let (first, second) = parse_wires(INPUT).unwrap();
-        assert_eq!(part2(&first, &second).unwrap(), 15622);
-    }
 }
@@ -1,19 +1,25 @@
 use std::cmp::Ordering;
-use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day04.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day04;
 
-    let (begin, end) = range(INPUT)?;
+impl Solution for Day04 {
+    type Input = (usize, usize);
 
-    writeln!(res, "part 1: {}", part1(begin, end)?)?;
-    writeln!(res, "part 2: {}", part2(begin, end)?)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        range(input)
+    }
 
-    Ok(res)
+    fn part1(&(begin, end): &Self::Input) -> Result<Answer> {
+        Ok(part1(begin, end)?.into())
+    }
+
+    fn part2(&(begin, end): &Self::Input) -> Result<Answer> {
+        Ok(part2(begin, end)?.into())
+    }
 }
 
 fn range(input: &str) -> Result<(usize, usize)> {
@@ -135,12 +141,6 @@ mod tests {
         assert_eq!(part1(begin, end).unwrap(), 0);
     }
 
-    #[test]
-    fn part1_real() {
-        let (begin, end) = range(INPUT).unwrap();
-        assert_eq!(part1(begin, end).unwrap(), 1729);
-    }
-
     #[test]
     fn part2_provided() {
         let (begin, end) = range("112233-112233").unwrap();
@@ -150,10 +150,4 @@ mod tests {
         let (begin, end) = range("111122-111122").unwrap();
         assert_eq!(part2(begin, end).unwrap(), 1);
     }
-
-    #[test]
-    fn part2_real() {
-        let (begin, end) = range(INPUT).unwrap();
-        assert_eq!(part2(begin, end).unwrap(), 1172);
-    }
 }
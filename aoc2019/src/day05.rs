@@ -1,45 +1,37 @@
-use std::fmt::Write;
-
 use anyhow::{Context, Result};
 
-use crate::intcode::Intcode;
+use aoc::{Answer, Solution};
+
+use crate::intcode::{parse_memory, Intcode};
+
+pub struct Day05;
 
-const INPUT: &str = include_str!("../input/day05.txt");
+impl Solution for Day05 {
+    type Input = Vec<i64>;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_memory(input)
+    }
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn part1(memory: &Self::Input) -> Result<Answer> {
+        Ok(part1(memory.clone())?.into())
+    }
 
-    Ok(res)
+    fn part2(memory: &Self::Input) -> Result<Answer> {
+        Ok(part2(memory.clone())?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<i64> {
-    let mut intcode = Intcode::new(input)?;
+fn part1(memory: Vec<i64>) -> Result<i64> {
+    let mut intcode = Intcode::with_memory(memory);
     intcode.add_input(1);
     intcode.run()?;
     intcode.get_last_output().context("intcode gave no output")
 }
 
-fn part2(input: &str) -> Result<i64> {
-    let mut intcode = Intcode::new(input)?;
+fn part2(memory: Vec<i64>) -> Result<i64> {
+    let mut intcode = Intcode::with_memory(memory);
     intcode.add_input(5);
     intcode.run()?;
     intcode.get_last_output().context("intcode gave no output")
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 16225258);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2808771);
-    }
-}
@@ -1,19 +1,41 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fmt::Write;
 use std::iter;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day06.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day06;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day06 {
+    type Input = HashMap<String, String>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_orbits(input)
+    }
+
+    fn part1(orbits: &Self::Input) -> Result<Answer> {
+        Ok(part1(orbits).into())
+    }
+
+    fn part2(orbits: &Self::Input) -> Result<Answer> {
+        Ok(part2(orbits).into())
+    }
+}
+
+/// Maps each object to the one it orbits.
+fn parse_orbits(input: &str) -> Result<HashMap<String, String>> {
+    input
+        .lines()
+        .map(str::trim_end)
+        .map(|line| {
+            let paren = line
+                .find(')')
+                .with_context(|| format!("couldn't find `)` in line: {}", line))?;
+            Ok((line[paren + 1..].to_string(), line[..paren].to_string()))
+        })
+        .collect()
 }
 
 fn count_orbits(
@@ -34,43 +56,21 @@ fn count_orbits(
     }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let orbits = input
-        .lines()
-        .map(str::trim_end)
-        .map(|line| {
-            let paren = line
-                .find(')')
-                .with_context(|| format!("couldn't find `)` in line: {}", line))?;
-            Ok((line[paren + 1..].to_string(), line[..paren].to_string()))
-        })
-        .collect::<Result<HashMap<String, String>>>()?;
-
+fn part1(orbits: &HashMap<String, String>) -> u64 {
     let mut cache = HashMap::new();
-    Ok(orbits
+    orbits
         .keys()
-        .map(|k| count_orbits(k, &orbits, &mut cache))
-        .sum())
+        .map(|k| count_orbits(k, orbits, &mut cache))
+        .sum()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let orbits = input
-        .lines()
-        .map(str::trim_end)
-        .map(|line| {
-            let paren = line
-                .find(')')
-                .with_context(|| format!("couldn't find `)` in line: {}", line))?;
-            Ok((line[paren + 1..].to_string(), line[..paren].to_string()))
-        })
-        .collect::<Result<HashMap<String, String>>>()?;
-
+fn part2(orbits: &HashMap<String, String>) -> usize {
     let succ = |key: &String| orbits.get(key).cloned();
 
     let you_path = iter::successors(Some("YOU".to_string()), succ).collect::<HashSet<_>>();
     let santa_path = iter::successors(Some("SAN".to_string()), succ).collect::<HashSet<_>>();
 
-    Ok(you_path.symmetric_difference(&santa_path).count() - 2)
+    you_path.symmetric_difference(&santa_path).count() - 2
 }
 
 #[cfg(test)]
@@ -107,21 +107,11 @@ I)SAN
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 42);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 140608);
+        assert_eq!(part1(&parse_orbits(PROVIDED1).unwrap()), 42);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED2).unwrap(), 4);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 337);
+        assert_eq!(part2(&parse_orbits(PROVIDED2).unwrap()), 4);
     }
 }
@@ -1,55 +1,34 @@
-use std::collections::VecDeque;
-use std::fmt::Write;
-
 use anyhow::{bail, Context, Result};
 
-use crate::intcode::{parse_memory, Intcode};
-
-const INPUT: &str = include_str!("../input/day07.txt");
+use aoc::combinatorics::permutations;
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+use crate::intcode::{parse_memory, Intcode};
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+pub struct Day07;
 
-    Ok(res)
-}
+impl Solution for Day07 {
+    type Input = Vec<i64>;
 
-fn permutations_rec(used: &mut Vec<i64>, available: &mut VecDeque<i64>, res: &mut Vec<Vec<i64>>) {
-    if available.is_empty() {
-        res.push(used.clone());
-    } else {
-        for _ in 0..available.len() {
-            used.push(available.pop_front().unwrap());
-            permutations_rec(used, available, res);
-            available.push_back(used.pop().unwrap());
-        }
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_memory(input)
     }
-}
-
-fn permutations(array: &[i64]) -> Vec<Vec<i64>> {
-    let mut res = Vec::new();
 
-    permutations_rec(
-        &mut Vec::new(),
-        &mut VecDeque::from(array.to_vec()),
-        &mut res,
-    );
+    fn part1(memory: &Self::Input) -> Result<Answer> {
+        Ok(part1(memory)?.into())
+    }
 
-    res
+    fn part2(memory: &Self::Input) -> Result<Answer> {
+        Ok(part2(memory)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<i64> {
-    let memory = parse_memory(input)?;
-
-    let combinations = permutations(&[0, 1, 2, 3, 4]);
-
+fn part1(memory: &[i64]) -> Result<i64> {
     let mut res = 0;
-    for combination in combinations {
+    for combination in permutations(&[0, 1, 2, 3, 4]) {
         let mut output = 0;
         for phase in &combination {
-            let mut intcode = Intcode::with_memory(memory.clone());
+            let mut intcode = Intcode::with_memory(memory.to_vec());
 
             intcode.add_input(*phase);
             intcode.add_input(output);
@@ -67,19 +46,15 @@ fn part1(input: &str) -> Result<i64> {
     Ok(res)
 }
 
-fn part2(input: &str) -> Result<i64> {
-    let memory = parse_memory(input)?;
-
-    let combinations = permutations(&[5, 6, 7, 8, 9]);
-
+fn part2(memory: &[i64]) -> Result<i64> {
     let mut res = 0;
-    for combination in combinations {
+    for combination in permutations(&[5, 6, 7, 8, 9]) {
         let intcodes = &mut [
-            Intcode::with_memory(memory.clone()),
-            Intcode::with_memory(memory.clone()),
-            Intcode::with_memory(memory.clone()),
-            Intcode::with_memory(memory.clone()),
-            Intcode::with_memory(memory.clone()),
+            Intcode::with_memory(memory.to_vec()),
+            Intcode::with_memory(memory.to_vec()),
+            Intcode::with_memory(memory.to_vec()),
+            Intcode::with_memory(memory.to_vec()),
+            Intcode::with_memory(memory.to_vec()),
         ];
 
         for (phase, intcode) in combination.iter().zip(intcodes.iter_mut()) {
@@ -138,14 +113,9 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 43210);
-        assert_eq!(part1(PROVIDED2).unwrap(), 54321);
-        assert_eq!(part1(PROVIDED3).unwrap(), 65210);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 844468);
+        assert_eq!(part1(&parse_memory(PROVIDED1).unwrap()).unwrap(), 43210);
+        assert_eq!(part1(&parse_memory(PROVIDED2).unwrap()).unwrap(), 54321);
+        assert_eq!(part1(&parse_memory(PROVIDED3).unwrap()).unwrap(), 65210);
     }
 
     const PROVIDED4: &str =
@@ -154,12 +124,7 @@ mod tests {
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED4).unwrap(), 139629729);
-        assert_eq!(part2(PROVIDED5).unwrap(), 18216);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 4215746);
+        assert_eq!(part2(&parse_memory(PROVIDED4).unwrap()).unwrap(), 139629729);
+        assert_eq!(part2(&parse_memory(PROVIDED5).unwrap()).unwrap(), 18216);
     }
 }
@@ -1,23 +1,29 @@
-use std::fmt::{self, Write};
+use std::fmt;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
 
+use aoc::{Answer, Solution};
+
 const IMG_WIDTH: usize = 25;
 const IMG_HEIGHT: usize = 6;
 
-const INPUT: &str = include_str!("../input/day08.txt");
+pub struct Day08;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128 + IMG_HEIGHT * IMG_WIDTH);
+impl Solution for Day08 {
+    type Input = Image;
 
-    let image: Image = INPUT.parse()?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
 
-    writeln!(res, "part 1: {}", part1(&image)?)?;
-    writeln!(res, "part 2:")?;
-    part2(&image, &mut res)?;
+    fn part1(image: &Self::Input) -> Result<Answer> {
+        Ok(part1(image)?.into())
+    }
 
-    Ok(res)
+    fn part2(image: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Grid(part2(image)))
+    }
 }
 
 fn part1(image: &Image) -> Result<usize> {
@@ -44,16 +50,15 @@ fn part1(image: &Image) -> Result<usize> {
     Ok(one_count * two_count)
 }
 
-fn part2(image: &Image, res: &mut String) -> Result<()> {
-    writeln!(res, "{}", image)?;
-    Ok(())
+fn part2(image: &Image) -> String {
+    image.to_string()
 }
 
 struct Layer {
     pixels: Vec<Vec<u8>>,
 }
 
-struct Image {
+pub struct Image {
     layers: Vec<Layer>,
     result: Vec<Vec<u8>>,
 }
@@ -109,14 +114,3 @@ impl FromStr for Image {
         Ok(Image { layers, result })
     }
 }
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn part1_real() {
-        let image: Image = INPUT.parse().unwrap();
-        assert_eq!(part1(&image).unwrap(), 1848);
-    }
-}
@@ -1,20 +1,25 @@
-use std::fmt::Write;
-
 use anyhow::{Context, Result};
 
+use aoc::{Answer, Solution};
+
 use crate::intcode::{parse_memory, Intcode};
 
-const INPUT: &str = include_str!("../input/day09.txt");
+pub struct Day09;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+impl Solution for Day09 {
+    type Input = Vec<i64>;
 
-    let memory = parse_memory(INPUT)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_memory(input)
+    }
 
-    writeln!(res, "part 1: {}", part1(memory.clone())?)?;
-    writeln!(res, "part 2: {}", part2(memory)?)?;
+    fn part1(memory: &Self::Input) -> Result<Answer> {
+        Ok(part1(memory.clone())?.into())
+    }
 
-    Ok(res)
+    fn part2(memory: &Self::Input) -> Result<Answer> {
+        Ok(part2(memory.clone())?.into())
+    }
 }
 
 fn part1(memory: Vec<i64>) -> Result<i64> {
@@ -63,16 +68,4 @@ mod tests {
         intcode.run().unwrap();
         assert!(intcode.output[0] == 1_125_899_906_842_624);
     }
-
-    #[test]
-    fn part1_real() {
-        let memory = parse_memory(INPUT).unwrap();
-        assert_eq!(part1(memory).unwrap(), 3_533_056_970);
-    }
-
-    #[test]
-    fn part2_real() {
-        let memory = parse_memory(INPUT).unwrap();
-        assert_eq!(part2(memory).unwrap(), 72_852);
-    }
 }
@@ -1,16 +1,36 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day10.txt");
-
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+use aoc::math::gcd;
+use aoc::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Asteroid>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut asteroids = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            for (j, c) in line.chars().enumerate() {
+                if c == '#' {
+                    asteroids.push(Asteroid {
+                        pos: Position {
+                            x: j as i64,
+                            y: i as i64,
+                        },
+                    })
+                }
+            }
+        }
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
+        Ok(asteroids)
+    }
 
-    Ok(res)
+    fn part1(asteroids: &Self::Input) -> Result<Answer> {
+        Ok(part1(asteroids)?.into())
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -20,38 +40,16 @@ struct Position {
 }
 
 #[derive(Debug, PartialEq)]
-struct Asteroid {
+pub struct Asteroid {
     pos: Position,
 }
 
-fn gcd(a: i64, b: i64) -> i64 {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
-    }
-}
-
-fn part1(input: &str) -> Result<usize> {
-    let mut asteroids = Vec::new();
-    for (i, line) in input.lines().enumerate() {
-        for (j, c) in line.chars().enumerate() {
-            if c == '#' {
-                asteroids.push(Asteroid {
-                    pos: Position {
-                        x: j as i64,
-                        y: i as i64,
-                    },
-                })
-            }
-        }
-    }
-
+fn part1(asteroids: &[Asteroid]) -> Result<usize> {
     let mut best = None;
-    for a in &asteroids {
+    for a in asteroids {
         let mut set = HashSet::new();
 
-        for b in &asteroids {
+        for b in asteroids {
             if a == b {
                 continue;
             }
@@ -61,10 +59,7 @@ fn part1(input: &str) -> Result<usize> {
                 y: b.pos.y - a.pos.y,
             };
 
-            let mut div = gcd(direction.x, direction.y);
-            if div < 0 {
-                div *= -1;
-            }
+            let div = gcd(direction.x, direction.y);
 
             set.insert(Position {
                 x: direction.x / div,
@@ -159,15 +154,10 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 8);
-        assert_eq!(part1(PROVIDED2).unwrap(), 33);
-        assert_eq!(part1(PROVIDED3).unwrap(), 35);
-        assert_eq!(part1(PROVIDED4).unwrap(), 41);
-        assert_eq!(part1(PROVIDED5).unwrap(), 210);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 214);
+        assert_eq!(part1(&Day10::parse(PROVIDED1).unwrap()).unwrap(), 8);
+        assert_eq!(part1(&Day10::parse(PROVIDED2).unwrap()).unwrap(), 33);
+        assert_eq!(part1(&Day10::parse(PROVIDED3).unwrap()).unwrap(), 35);
+        assert_eq!(part1(&Day10::parse(PROVIDED4).unwrap()).unwrap(), 41);
+        assert_eq!(part1(&Day10::parse(PROVIDED5).unwrap()).unwrap(), 210);
     }
 }
@@ -3,22 +3,30 @@ use std::fmt::Write;
 
 use anyhow::{bail, Result};
 
-use crate::intcode::Intcode;
+use aoc::{Answer, Solution};
 
-const INPUT: &str = include_str!("../input/day11.txt");
+use crate::intcode::{parse_memory, Intcode};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day11;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2:")?;
-    part2(INPUT, &mut res)?;
+impl Solution for Day11 {
+    type Input = Vec<i64>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_memory(input)
+    }
+
+    fn part1(memory: &Self::Input) -> Result<Answer> {
+        Ok(part1(memory.clone())?.into())
+    }
+
+    fn part2(memory: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Grid(part2(memory.clone())?))
+    }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let mut robot = Robot::new(input)?;
+fn part1(memory: Vec<i64>) -> Result<usize> {
+    let mut robot = Robot::new(memory);
     let mut board = HashMap::new();
 
     robot.run(&mut board)?;
@@ -26,15 +34,18 @@ fn part1(input: &str) -> Result<usize> {
     Ok(board.len())
 }
 
-fn part2(input: &str, res: &mut String) -> Result<()> {
-    let mut robot = Robot::new(input)?;
+fn part2(memory: Vec<i64>) -> Result<String> {
+    let mut robot = Robot::new(memory);
     let mut board = HashMap::new();
 
     board.insert(robot.pos, true);
 
     robot.run(&mut board)?;
 
-    write_board(res, board)
+    let mut res = String::with_capacity(128);
+    write_board(&mut res, board)?;
+
+    Ok(res)
 }
 
 fn write_board(res: &mut String, board: HashMap<Position, bool>) -> Result<()> {
@@ -86,14 +97,12 @@ struct Robot {
 }
 
 impl Robot {
-    fn new(program: &str) -> Result<Self> {
-        let intcode = Intcode::new(program)?;
-
-        Ok(Robot {
+    fn new(program: Vec<i64>) -> Self {
+        Robot {
             pos: Position { x: 0, y: 0 },
             dir: Direction::Up,
-            brain: intcode,
-        })
+            brain: Intcode::with_memory(program),
+        }
     }
 
     fn run(&mut self, board: &mut HashMap<Position, bool>) -> Result<()> {
@@ -155,24 +164,3 @@ impl Robot {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const RES2: &str = include_str!("../input/day11_res.txt");
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1883);
-    }
-
-    #[test]
-    fn part2_real() {
-        let mut res = String::with_capacity(RES2.len());
-
-        part2(INPUT, &mut res).unwrap();
-        assert_eq!(res.len(), RES2.len());
-        assert_eq!(res, RES2);
-    }
-}
@@ -1,42 +1,28 @@
 use std::cmp::Ordering;
-use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{anyhow, Context, Result};
 
-const INPUT: &str = include_str!("../input/day12.txt");
+use aoc::combinatorics::pairs;
+use aoc::math::lcm;
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day12;
 
-    let planets = parse_planets(INPUT)?;
+impl Solution for Day12 {
+    type Input = Vec<Planet>;
 
-    writeln!(res, "part 1: {}", part1(planets.clone(), 1000)?)?;
-    writeln!(res, "part 2: {}", part2(planets)?)?;
-
-    Ok(res)
-}
-
-fn generate_pairs_rec(res: &mut Vec<(usize, usize)>, tmp: &mut Vec<usize>, todo: usize, n: usize) {
-    if tmp.len() == 2 {
-        res.push((tmp[0], tmp[1]));
-        return;
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_planets(input)
     }
 
-    for i in todo..n {
-        tmp.push(i);
-        generate_pairs_rec(res, tmp, i + 1, n);
-        tmp.pop();
+    fn part1(planets: &Self::Input) -> Result<Answer> {
+        Ok(part1(planets.clone(), 1000)?.into())
     }
-}
-
-fn generate_pairs(n: usize) -> Vec<(usize, usize)> {
-    let mut res = Vec::new();
-    let mut tmp = Vec::new();
-
-    generate_pairs_rec(&mut res, &mut tmp, 0, n);
 
-    res
+    fn part2(planets: &Self::Input) -> Result<Answer> {
+        Ok(part2(planets.clone())?.into())
+    }
 }
 
 fn parse_planets(input: &str) -> Result<Vec<Planet>> {
@@ -44,11 +30,9 @@ fn parse_planets(input: &str) -> Result<Vec<Planet>> {
 }
 
 fn part1(mut planets: Vec<Planet>, steps: usize) -> Result<u64> {
-    let pairs = generate_pairs(planets.len());
-
     for _ in 0..steps {
         // update velocity
-        for pair in pairs.iter().copied() {
+        for pair in pairs(0..planets.len()) {
             let (begin, end) = planets.split_at_mut(pair.1);
             let first = &mut begin[pair.0];
             let second = &mut end[0];
@@ -99,30 +83,16 @@ fn part1(mut planets: Vec<Planet>, steps: usize) -> Result<u64> {
     Ok(planets.iter().map(Planet::total_energy).sum())
 }
 
-fn gcd(a: usize, b: usize) -> usize {
-    if a == 0 {
-        return b;
-    }
-
-    gcd(b % a, a)
-}
-
-fn lcm(a: usize, b: usize) -> usize {
-    (a * b) / gcd(a, b)
-}
-
 fn part2(mut planets: Vec<Planet>) -> Result<usize> {
     let first_state = planets.clone();
 
-    let pairs = generate_pairs(planets.len());
-
     let mut x_step = None;
     let mut y_step = None;
     let mut z_step = None;
 
     for i in 1.. {
         // update velocity
-        for pair in pairs.iter().copied() {
+        for pair in pairs(0..planets.len()) {
             let (begin, end) = planets.split_at_mut(pair.1);
             let first = &mut begin[pair.0];
             let second = &mut end[0];
@@ -212,7 +182,7 @@ struct Vec3 {
 }
 
 #[derive(Clone)]
-struct Planet {
+pub struct Planet {
     position: Vec3,
     velocity: Vec3,
 }
@@ -308,11 +278,6 @@ mod tests {
         assert_eq!(part1(parse_planets(PROVIDED2).unwrap(), 100).unwrap(), 1940);
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(parse_planets(INPUT).unwrap(), 1000).unwrap(), 14907);
-    }
-
     #[test]
     fn part2_provided() {
         assert_eq!(part2(parse_planets(PROVIDED1).unwrap()).unwrap(), 2772);
@@ -321,12 +286,4 @@ mod tests {
             4686774924
         );
     }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(
-            part2(parse_planets(INPUT).unwrap()).unwrap(),
-            467_081_194_429_464
-        );
-    }
 }
@@ -1,21 +1,29 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fmt::{self, Display, Write};
+use std::fmt::{self, Display};
 
 use anyhow::{bail, Result};
 
+use aoc::{Answer, Solution};
+
 use crate::intcode::{parse_memory, Intcode};
 
-const INPUT: &str = include_str!("../input/day13.txt");
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<i64>;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_memory(input)
+    }
 
-    let memory = parse_memory(INPUT)?;
-    writeln!(res, "part 1: {}", part1(memory.clone())?)?;
-    writeln!(res, "part 2: {}", part2(memory)?)?;
+    fn part1(memory: &Self::Input) -> Result<Answer> {
+        Ok(part1(memory.clone())?.into())
+    }
 
-    Ok(res)
+    fn part2(memory: &Self::Input) -> Result<Answer> {
+        Ok(part2(memory.clone())?.into())
+    }
 }
 
 fn part1(memory: Vec<i64>) -> Result<usize> {
@@ -129,20 +137,3 @@ impl Display for Tile {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part1_real() {
-        let memory = parse_memory(INPUT).unwrap();
-        assert_eq!(part1(memory).unwrap(), 298);
-    }
-
-    #[test]
-    fn part2_real() {
-        let memory = parse_memory(INPUT).unwrap();
-        assert_eq!(part2(memory).unwrap(), 13956);
-    }
-}
@@ -1,18 +1,26 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day14.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day14;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day14 {
+    type Input = HashMap<String, Recipe>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_recipes(input)
+    }
+
+    fn part1(recipes: &Self::Input) -> Result<Answer> {
+        Ok(part1(recipes)?.into())
+    }
+
+    fn part2(recipes: &Self::Input) -> Result<Answer> {
+        Ok(part2(recipes)?.into())
+    }
 }
 
 fn parse_recipes(input: &str) -> Result<HashMap<String, Recipe>> {
@@ -95,24 +103,21 @@ fn get_ore_cost(
     Ok(total)
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let recipes = parse_recipes(input)?;
+fn part1(recipes: &HashMap<String, Recipe>) -> Result<u64> {
     let mut inventory = HashMap::new();
 
-    get_ore_cost("FUEL".to_string(), 1, &recipes, &mut inventory)
+    get_ore_cost("FUEL".to_string(), 1, recipes, &mut inventory)
 }
 
-fn part2(input: &str) -> Result<u64> {
+fn part2(recipes: &HashMap<String, Recipe>) -> Result<u64> {
     let mut begin: u64 = 0;
     let mut end: u64 = 1_000_000_000_000;
 
-    let recipes = parse_recipes(input)?;
-
     while begin <= end {
         let mid = begin + (end - begin) / 2;
         let mut inventory = HashMap::new();
 
-        let ore_cost = get_ore_cost("FUEL".to_string(), mid, &recipes, &mut inventory)?;
+        let ore_cost = get_ore_cost("FUEL".to_string(), mid, recipes, &mut inventory)?;
         match ore_cost.cmp(&1_000_000_000_000) {
             Ordering::Greater => end = mid - 1,
             Ordering::Less => begin = mid + 1,
@@ -128,7 +133,7 @@ struct RecipeElem {
     amount: u64,
 }
 
-struct Recipe {
+pub struct Recipe {
     produced: u64,
     elems: Vec<RecipeElem>,
 }
@@ -200,27 +205,17 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 31);
-        assert_eq!(part1(PROVIDED2).unwrap(), 165);
-        assert_eq!(part1(PROVIDED3).unwrap(), 13312);
-        assert_eq!(part1(PROVIDED4).unwrap(), 180697);
-        assert_eq!(part1(PROVIDED5).unwrap(), 2210736);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 532506);
+        assert_eq!(part1(&parse_recipes(PROVIDED1).unwrap()).unwrap(), 31);
+        assert_eq!(part1(&parse_recipes(PROVIDED2).unwrap()).unwrap(), 165);
+        assert_eq!(part1(&parse_recipes(PROVIDED3).unwrap()).unwrap(), 13312);
+        assert_eq!(part1(&parse_recipes(PROVIDED4).unwrap()).unwrap(), 180697);
+        assert_eq!(part1(&parse_recipes(PROVIDED5).unwrap()).unwrap(), 2210736);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED3).unwrap(), 82892753);
-        assert_eq!(part2(PROVIDED4).unwrap(), 5586022);
-        assert_eq!(part2(PROVIDED5).unwrap(), 460664);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2595245);
+        assert_eq!(part2(&parse_recipes(PROVIDED3).unwrap()).unwrap(), 82892753);
+        assert_eq!(part2(&parse_recipes(PROVIDED4).unwrap()).unwrap(), 5586022);
+        assert_eq!(part2(&parse_recipes(PROVIDED5).unwrap()).unwrap(), 460664);
     }
 }
@@ -37,6 +37,7 @@ pub struct Intcode {
 }
 
 impl Intcode {
+    #[cfg(test)]
     pub fn new(input: &str) -> Result<Self> {
         let memory = parse_memory(input)?;
 
@@ -16,3 +16,5 @@ pub mod day11;
 pub mod day12;
 pub mod day13;
 pub mod day14;
+
+aoc::year!();
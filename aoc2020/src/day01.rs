@@ -1,37 +1,35 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::{anyhow, Result};
 
-const INPUT: &str = include_str!("../input/day01.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day01;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day01 {
+    type Input = Vec<i64>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(|line| Ok(line.parse()?)).collect()
+    }
 
-fn part1(input: &str) -> Result<i64> {
-    let entries = input
-        .lines()
-        .map(|line| line.parse::<i64>().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<i64>>>()?;
+    fn part1(entries: &Self::Input) -> Result<Answer> {
+        Ok(part1(entries)?.into())
+    }
 
-    let (a, b) = find_2020_2_sum(&entries)?;
+    fn part2(entries: &Self::Input) -> Result<Answer> {
+        Ok(part2(entries)?.into())
+    }
+}
+
+fn part1(entries: &[i64]) -> Result<i64> {
+    let (a, b) = find_2020_2_sum(entries)?;
 
     Ok(a * b)
 }
 
-fn part2(input: &str) -> Result<i64> {
-    let entries = input
-        .lines()
-        .map(|line| line.parse::<i64>().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<_>>>()?;
-
-    let (a, b, c) = find_2020_3_sum(&entries)?;
+fn part2(entries: &[i64]) -> Result<i64> {
+    let (a, b, c) = find_2020_3_sum(entries)?;
 
     Ok(a * b * c)
 }
@@ -85,19 +83,9 @@ mod tests {
         assert_eq!(a + b, 2020);
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1014171);
-    }
-
     #[test]
     fn part2_provided() {
         let (a, b, c) = find_2020_3_sum(PROVIDED).unwrap();
         assert_eq!(a + b + c, 2020);
     }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 46584630);
-    }
 }
@@ -1,12 +1,11 @@
-use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day02.txt");
+use aoc::{Answer, Solution};
 
 #[derive(Debug)]
-struct PassPolicy {
+pub struct PassPolicy {
     min_bound: usize,
     max_bound: usize,
     letter: u8,
@@ -80,37 +79,36 @@ impl FromStr for PassPolicy {
     }
 }
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day02;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day02 {
+    type Input = Vec<PassPolicy>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let policies = input
-        .lines()
-        .map(str::parse::<PassPolicy>)
-        .collect::<Result<Vec<PassPolicy>>>()?;
+    fn part1(policies: &Self::Input) -> Result<Answer> {
+        Ok(part1(policies).into())
+    }
 
-    Ok(policies
+    fn part2(policies: &Self::Input) -> Result<Answer> {
+        Ok(part2(policies).into())
+    }
+}
+
+fn part1(policies: &[PassPolicy]) -> usize {
+    policies
         .iter()
         .filter(|policy| policy.is_valid_part1())
-        .count())
+        .count()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let policies = input
-        .lines()
-        .map(str::parse::<PassPolicy>)
-        .collect::<Result<Vec<PassPolicy>>>()?;
-
-    Ok(policies
+fn part2(policies: &[PassPolicy]) -> usize {
+    policies
         .iter()
         .filter(|policy| policy.is_valid_part2())
-        .count())
+        .count()
 }
 
 #[cfg(test)]
@@ -128,20 +126,10 @@ mod tests {
         assert!(PROVIDED3.parse::<PassPolicy>().unwrap().is_valid_part1());
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 556);
-    }
-
     #[test]
     fn part2_provided() {
         assert!(PROVIDED1.parse::<PassPolicy>().unwrap().is_valid_part2());
         assert!(!PROVIDED2.parse::<PassPolicy>().unwrap().is_valid_part2());
         assert!(!PROVIDED3.parse::<PassPolicy>().unwrap().is_valid_part2());
     }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 605);
-    }
 }
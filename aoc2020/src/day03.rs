@@ -1,36 +1,43 @@
-use std::fmt::Write;
-use std::iter::FromIterator;
-use std::ops::Index;
-use std::str::FromStr;
-
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day03.txt");
+use aoc::{Answer, Grid, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day03;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day03 {
+    type Input = Forest;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_forest(input)
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let forest = input.parse()?;
+    fn part1(forest: &Self::Input) -> Result<Answer> {
+        Ok(part1(forest).into())
+    }
 
-    Ok(count_trees(&forest, (3, 1)))
+    fn part2(forest: &Self::Input) -> Result<Answer> {
+        Ok(part2(forest).into())
+    }
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let forest = input.parse()?;
+fn part1(forest: &Forest) -> usize {
+    count_trees(forest, (3, 1))
+}
 
+fn part2(forest: &Forest) -> usize {
     let slopes = &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
 
-    Ok(slopes
+    slopes
         .iter()
-        .map(|slope| count_trees(&forest, *slope))
-        .product())
+        .map(|slope| count_trees(forest, *slope))
+        .product()
+}
+
+/// The forest repeats to the right, which `Grid::get_wrapping` takes care of.
+type Forest = Grid<bool>;
+
+fn parse_forest(input: &str) -> Result<Forest> {
+    Grid::parse_with(input, |c| Ok(c == '#'))
 }
 
 fn count_trees(forest: &Forest, (right, down): (usize, usize)) -> usize {
@@ -39,70 +46,10 @@ fn count_trees(forest: &Forest, (right, down): (usize, usize)) -> usize {
 
     vertical_range
         .zip(horizontal_range)
-        .filter(|(i, j)| forest[*i][*j])
+        .filter(|&(i, j)| *forest.get_wrapping((j as isize, i as isize)))
         .count()
 }
 
-#[derive(Debug)]
-struct ForestLine {
-    trees: Vec<bool>,
-}
-
-impl Index<usize> for ForestLine {
-    type Output = bool;
-
-    fn index(&self, i: usize) -> &Self::Output {
-        &self.trees[i % self.trees.len()]
-    }
-}
-
-impl FromIterator<bool> for ForestLine {
-    fn from_iter<I>(iter: I) -> Self
-    where
-        I: IntoIterator<Item = bool>,
-    {
-        Self {
-            trees: iter.into_iter().collect(),
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Forest {
-    trees: Vec<ForestLine>,
-}
-
-impl Forest {
-    fn height(&self) -> usize {
-        self.trees.len()
-    }
-}
-
-impl Index<usize> for Forest {
-    type Output = ForestLine;
-
-    fn index(&self, i: usize) -> &Self::Output {
-        if i >= self.height() {
-            panic!("forest is of size {}, tried to access {}", self.height(), i);
-        }
-
-        &self.trees[i]
-    }
-}
-
-impl FromStr for Forest {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let trees = s
-            .lines()
-            .map(|line| line.chars().map(|c| matches!(c, '#')).collect())
-            .collect();
-
-        Ok(Self { trees })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,17 +58,12 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 7);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 242);
+        assert_eq!(part1(&parse_forest(PROVIDED).unwrap()), 7);
     }
 
     #[test]
     fn part2_provided() {
-        let forest = PROVIDED.parse().unwrap();
+        let forest = parse_forest(PROVIDED).unwrap();
 
         assert_eq!(count_trees(&forest, (1, 1)), 2);
         assert_eq!(count_trees(&forest, (3, 1)), 7);
@@ -129,11 +71,6 @@ mod tests {
         assert_eq!(count_trees(&forest, (7, 1)), 4);
         assert_eq!(count_trees(&forest, (1, 2)), 2);
 
-        assert_eq!(part2(PROVIDED).unwrap(), 336);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2265549792);
+        assert_eq!(part2(&forest), 336);
     }
 }
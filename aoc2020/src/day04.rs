@@ -1,19 +1,27 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day04.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day04;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_passports(input)
+    }
+
+    fn part1(passports: &Self::Input) -> Result<Answer> {
+        Ok(part1(passports).into())
+    }
+
+    fn part2(passports: &Self::Input) -> Result<Answer> {
+        Ok(part2(passports).into())
+    }
 }
 
 fn get_passports(input: &str) -> Result<Vec<Passport>> {
@@ -37,24 +45,20 @@ fn get_passports(input: &str) -> Result<Vec<Passport>> {
     Ok(passports)
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let passports = get_passports(input)?;
-
-    Ok(passports.iter().filter(|p| p.is_complete()).count())
+fn part1(passports: &[Passport]) -> usize {
+    passports.iter().filter(|p| p.is_complete()).count()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let passports = get_passports(input)?;
-
-    Ok(passports
-        .into_iter()
+fn part2(passports: &[Passport]) -> usize {
+    passports
+        .iter()
         .filter_map(Passport::complete)
         .filter(CompletePassport::is_valid)
-        .count())
+        .count()
 }
 
 #[derive(Debug)]
-struct Passport {
+pub struct Passport {
     byr: Option<String>,
     iyr: Option<String>,
     eyr: Option<String>,
@@ -77,15 +81,15 @@ impl Passport {
             && self.pid.is_some()
     }
 
-    fn complete(mut self) -> Option<CompletePassport> {
+    fn complete(&self) -> Option<CompletePassport> {
         Some(CompletePassport {
-            byr: self.byr.take()?,
-            iyr: self.iyr.take()?,
-            eyr: self.eyr.take()?,
-            hgt: self.hgt.take()?,
-            hcl: self.hcl.take()?,
-            ecl: self.ecl.take()?,
-            pid: self.pid.take()?,
+            byr: self.byr.clone()?,
+            iyr: self.iyr.clone()?,
+            eyr: self.eyr.clone()?,
+            hgt: self.hgt.clone()?,
+            hcl: self.hcl.clone()?,
+            ecl: self.ecl.clone()?,
+            pid: self.pid.clone()?,
         })
     }
 }
@@ -202,22 +206,12 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 2);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 192);
+        assert_eq!(part1(&get_passports(PROVIDED1).unwrap()), 2);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED2).unwrap(), 0);
-        assert_eq!(part2(PROVIDED3).unwrap(), 4);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 101);
+        assert_eq!(part2(&get_passports(PROVIDED2).unwrap()), 0);
+        assert_eq!(part2(&get_passports(PROVIDED3).unwrap()), 4);
     }
 }
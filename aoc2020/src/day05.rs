@@ -1,25 +1,28 @@
-use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{anyhow, bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day05.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day05;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day05 {
+    type Input = Vec<Seat>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(seats: &Self::Input) -> Result<Answer> {
+        Ok(part1(seats)?.into())
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let seats = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<Seat>>>()?;
+    fn part2(seats: &Self::Input) -> Result<Answer> {
+        Ok(part2(seats)?.into())
+    }
+}
 
+fn part1(seats: &[Seat]) -> Result<usize> {
     seats
         .iter()
         .map(Seat::id)
@@ -27,11 +30,8 @@ fn part1(input: &str) -> Result<usize> {
         .context("0 seats processed")
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut seats = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<Seat>>>()?;
+fn part2(seats: &[Seat]) -> Result<usize> {
+    let mut seats = seats.to_vec();
 
     // Seats will be sorted by lexicographical order of fields thanks to `derive(PartialOrd, Ord)`,
     // which should produce the same result as a manual implementation of `Ord` and `PartialOrd`
@@ -50,8 +50,8 @@ fn part2(input: &str) -> Result<usize> {
     Err(anyhow!("didn't find missing seat"))
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct Seat {
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Seat {
     row: usize,
     column: usize,
 }
@@ -123,14 +123,4 @@ mod tests {
         );
         assert_eq!(seat.id(), 820);
     }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 850);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 599);
-    }
 }
@@ -1,17 +1,25 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day06.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day06;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day06 {
+    type Input = Vec<Group>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        get_groups(input)
+    }
+
+    fn part1(groups: &Self::Input) -> Result<Answer> {
+        Ok(part1(groups).into())
+    }
+
+    fn part2(groups: &Self::Input) -> Result<Answer> {
+        Ok(part2(groups).into())
+    }
 }
 
 fn get_groups(input: &str) -> Result<Vec<Group>> {
@@ -38,25 +46,21 @@ fn get_groups(input: &str) -> Result<Vec<Group>> {
     Ok(groups)
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let groups = get_groups(input)?;
-
-    Ok(groups
+fn part1(groups: &[Group]) -> usize {
+    groups
         .iter()
         .map(|group| group.unique_positive_answers().len())
-        .sum())
+        .sum()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let groups = get_groups(input)?;
-
-    Ok(groups
+fn part2(groups: &[Group]) -> usize {
+    groups
         .iter()
         .map(|group| group.common_positive_answers().len())
-        .sum())
+        .sum()
 }
 
-struct Group {
+pub struct Group {
     answers: Vec<HashSet<u8>>,
 }
 
@@ -85,21 +89,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 11);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 6382);
+        assert_eq!(part1(&get_groups(PROVIDED).unwrap()), 11);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 6);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 3197);
+        assert_eq!(part2(&get_groups(PROVIDED).unwrap()), 6);
     }
 }
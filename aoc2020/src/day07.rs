@@ -1,27 +1,29 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day07.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day07;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day07 {
+    type Input = Vec<BagRule>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let bag_rules = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<BagRule>>>()
-        .unwrap();
+    fn part1(bag_rules: &Self::Input) -> Result<Answer> {
+        Ok(part1(bag_rules).into())
+    }
 
+    fn part2(bag_rules: &Self::Input) -> Result<Answer> {
+        Ok(part2(bag_rules).into())
+    }
+}
+
+fn part1(bag_rules: &[BagRule]) -> usize {
     // create map with Key = color, Value = BagRule
     let bag_rules_map = bag_rules
         .iter()
@@ -33,19 +35,13 @@ fn part1(input: &str) -> Result<usize> {
     // NOTE: part 1 can also be solved efficiently by using a graph where a bag has its parents as
     //       neighbours, and then by computing the size of the sub graph accessible from 'shiny
     //       gold'
-    Ok(bag_rules
+    bag_rules
         .iter()
         .filter(|bag| bag.can_contain("shiny gold", &bag_rules_map, &mut memoized))
-        .count())
+        .count()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let bag_rules = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<BagRule>>>()
-        .unwrap();
-
+fn part2(bag_rules: &[BagRule]) -> usize {
     // create map with Key = color, Value = BagRule
     let bag_rules_map: HashMap<String, BagRule> = bag_rules
         .iter()
@@ -54,11 +50,11 @@ fn part2(input: &str) -> Result<usize> {
 
     let shiny_gold = &bag_rules_map["shiny gold"];
 
-    Ok(shiny_gold.num_inner_bags(&bag_rules_map))
+    shiny_gold.num_inner_bags(&bag_rules_map)
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct BagRule {
+pub struct BagRule {
     color: String,
     contains: Vec<(usize, String)>,
 }
@@ -166,11 +162,7 @@ mod tests {
 
     #[test]
     fn part1_provided_parse() {
-        let bag_rules = PROVIDED1
-            .lines()
-            .map(str::parse)
-            .collect::<Result<Vec<BagRule>>>()
-            .unwrap();
+        let bag_rules = Day07::parse(PROVIDED1).unwrap();
 
         let expected = vec![
             BagRule {
@@ -235,22 +227,12 @@ mod tests {
 
     #[test]
     fn part1_provided_compute() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 4);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 272);
+        assert_eq!(part1(&Day07::parse(PROVIDED1).unwrap()), 4);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED1).unwrap(), 32);
-        assert_eq!(part2(PROVIDED2).unwrap(), 126);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 172246);
+        assert_eq!(part2(&Day07::parse(PROVIDED1).unwrap()), 32);
+        assert_eq!(part2(&Day07::parse(PROVIDED2).unwrap()), 126);
     }
 }
@@ -1,26 +1,29 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::{anyhow, bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day08.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day08;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day08 {
+    type Input = Vec<Instruction>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
 
-fn part1(input: &str) -> Result<i64> {
-    let instructions = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<Instruction>>>()?;
+    fn part1(instructions: &Self::Input) -> Result<Answer> {
+        Ok(part1(instructions)?.into())
+    }
 
-    let mut interpreter = Interpreter::new(instructions);
+    fn part2(instructions: &Self::Input) -> Result<Answer> {
+        Ok(part2(instructions)?.into())
+    }
+}
+
+fn part1(instructions: &[Instruction]) -> Result<i64> {
+    let mut interpreter = Interpreter::new(instructions.to_vec());
 
     Ok(match interpreter.run() {
         ExitStatus::InfiniteLoop(value) => value,
@@ -28,14 +31,9 @@ fn part1(input: &str) -> Result<i64> {
     })
 }
 
-fn part2(input: &str) -> Result<i64> {
-    let instructions = input
-        .lines()
-        .map(str::parse)
-        .collect::<Result<Vec<Instruction>>>()?;
-
+fn part2(instructions: &[Instruction]) -> Result<i64> {
     for idx in 0..instructions.len() {
-        let mut instructions = instructions.clone();
+        let mut instructions = instructions.to_vec();
 
         match instructions[idx] {
             Instruction::Acc(_) => continue,
@@ -103,7 +101,7 @@ impl Interpreter {
 }
 
 #[derive(Clone)]
-enum Instruction {
+pub enum Instruction {
     Acc(i64),
     Jmp(i64),
     Nop(i64),
@@ -137,21 +135,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 5);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1675);
+        assert_eq!(part1(&Day08::parse(PROVIDED).unwrap()).unwrap(), 5);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 8);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 1532);
+        assert_eq!(part2(&Day08::parse(PROVIDED).unwrap()).unwrap(), 8);
     }
 }
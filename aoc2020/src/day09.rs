@@ -1,16 +1,23 @@
-use std::fmt::Write;
-
 use anyhow::{anyhow, Result};
 
-const INPUT: &str = include_str!("../input/day09.txt");
+use aoc::{Answer, Solution};
+
+pub struct Day09;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+impl Solution for Day09 {
+    type Input = Vec<u64>;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(|line| Ok(line.parse()?)).collect()
+    }
 
-    Ok(res)
+    fn part1(numbers: &Self::Input) -> Result<Answer> {
+        Ok(part1(numbers)?.into())
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Answer> {
+        Ok(part2(numbers)?.into())
+    }
 }
 
 fn find_pair_sum(data: &[u64], total: u64) -> Option<(u64, u64)> {
@@ -42,13 +49,8 @@ fn find_outlier(numbers: &[u64], preamble_size: usize) -> Result<(u64, usize)> {
     Err(anyhow!("couldn't find number with that property"))
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let numbers = input
-        .lines()
-        .map(|line| line.parse::<u64>().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<u64>, _>>()?;
-
-    let (solution, _) = find_outlier(&numbers, 25)?;
+fn part1(numbers: &[u64]) -> Result<u64> {
+    let (solution, _) = find_outlier(numbers, 25)?;
 
     Ok(solution)
 }
@@ -76,13 +78,8 @@ fn find_contiguous_range(numbers: &[u64], total: u64) -> Result<(u64, u64)> {
     Err(anyhow!("couldn't find number with that property"))
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let numbers = input
-        .lines()
-        .map(|line| line.parse::<u64>().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<u64>, _>>()?;
-
-    let (outlier, idx) = find_outlier(&numbers, 25)?;
+fn part2(numbers: &[u64]) -> Result<u64> {
+    let (outlier, idx) = find_outlier(numbers, 25)?;
 
     let (min, max) = find_contiguous_range(&numbers[..idx], outlier)?;
 
@@ -105,11 +102,6 @@ mod tests {
         assert_eq!(find_outlier(&numbers, 5).unwrap(), (127, 14));
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 248131121);
-    }
-
     #[test]
     fn part2_provided() {
         let numbers = PROVIDED
@@ -125,9 +117,4 @@ mod tests {
         assert_eq!(max, 47);
         assert_eq!(min + max, 62);
     }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 31580383);
-    }
 }
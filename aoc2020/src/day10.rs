@@ -1,39 +1,48 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 
 use anyhow::{bail, Result};
 
-const INPUT: &str = include_str!("../input/day10.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day10;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day10 {
+    type Input = Vec<u64>;
 
-    Ok(res)
-}
+    /// The adapters in the order they get chained, from the charging outlet to the device.
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut jolts = input
+            .lines()
+            .map(|line| Ok(line.parse()?))
+            .collect::<Result<Vec<u64>>>()?;
 
-fn part1(input: &str) -> Result<usize> {
-    let mut jolts = input
-        .lines()
-        .map(|line| line.parse().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<u64>>>()?;
+        if jolts.is_empty() {
+            bail!("input was empty!");
+        }
 
-    if jolts.is_empty() {
-        bail!("input was empty!");
-    }
+        // charging outlet can be added
+        jolts.insert(0, 0);
+
+        jolts.sort_unstable();
 
-    // charging outlet can be added
-    jolts.insert(0, 0);
+        // device is rated for max adapter + 3 jolts
+        let max_adapter = jolts[jolts.len() - 1];
+        let device_rating = max_adapter + 3;
+        jolts.push(device_rating);
+
+        Ok(jolts)
+    }
 
-    jolts.sort_unstable();
+    fn part1(jolts: &Self::Input) -> Result<Answer> {
+        Ok(part1(jolts).into())
+    }
 
-    // device is rated for max adapter + 3 jolts
-    let max_adapter = jolts[jolts.len() - 1];
-    let device_rating = max_adapter + 3;
-    jolts.push(device_rating);
+    fn part2(jolts: &Self::Input) -> Result<Answer> {
+        Ok(part2(jolts).into())
+    }
+}
 
+fn part1(jolts: &[u64]) -> usize {
     let mut differences: [usize; 4] = [0; 4];
 
     for window in jolts.windows(2) {
@@ -43,7 +52,7 @@ fn part1(input: &str) -> Result<usize> {
         differences[difference] += 1;
     }
 
-    Ok(differences[1] * differences[3])
+    differences[1] * differences[3]
 }
 
 fn find_possibilities(jolts: &[u64], possibilities: &mut HashMap<u64, usize>) -> usize {
@@ -77,29 +86,10 @@ fn find_possibilities(jolts: &[u64], possibilities: &mut HashMap<u64, usize>) ->
     possibilities_from_here
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut jolts = input
-        .lines()
-        .map(|line| line.parse().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<u64>>>()?;
-
-    if jolts.is_empty() {
-        bail!("input was empty!");
-    }
-
-    // charging outlet can be added
-    jolts.insert(0, 0);
-
-    jolts.sort_unstable();
-
-    // device is rated for max adapter + 3 jolts
-    let max_adapter = jolts[jolts.len() - 1];
-    let device_rating = max_adapter + 3;
-    jolts.push(device_rating);
-
+fn part2(jolts: &[u64]) -> usize {
     let mut possibilities = HashMap::new();
 
-    Ok(find_possibilities(&jolts, &mut possibilities))
+    find_possibilities(jolts, &mut possibilities)
 }
 
 #[cfg(test)]
@@ -111,23 +101,13 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 7 * 5);
-        assert_eq!(part1(PROVIDED2).unwrap(), 22 * 10);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 2112);
+        assert_eq!(part1(&Day10::parse(PROVIDED1).unwrap()), 7 * 5);
+        assert_eq!(part1(&Day10::parse(PROVIDED2).unwrap()), 22 * 10);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED1).unwrap(), 8);
-        assert_eq!(part2(PROVIDED2).unwrap(), 19208);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 3022415986688);
+        assert_eq!(part2(&Day10::parse(PROVIDED1).unwrap()), 8);
+        assert_eq!(part2(&Day10::parse(PROVIDED2).unwrap()), 19208);
     }
 }
@@ -1,34 +1,42 @@
-use std::fmt::Write;
-
 use anyhow::{anyhow, Result};
 
-const INPUT: &str = include_str!("../input/day11.txt");
+use aoc::grid::{Point, DIRECTIONS8};
+use aoc::{Answer, Grid, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Layout;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn part1(layout: &Self::Input) -> Result<Answer> {
+        Ok(part1(layout).into())
+    }
 
-    Ok(res)
+    fn part2(layout: &Self::Input) -> Result<Answer> {
+        Ok(part2(layout).into())
+    }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let mut layout: Layout = input.parse()?;
+fn part1(layout: &Layout) -> usize {
+    let mut layout = layout.clone();
 
     let occupied_threshold = 4;
     layout.converge(occupied_threshold, Layout::count_adjacent);
 
-    Ok(layout.occupied_seats())
+    layout.occupied_seats()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut layout: Layout = input.parse()?;
+fn part2(layout: &Layout) -> usize {
+    let mut layout = layout.clone();
 
     let occupied_threshold = 5;
     layout.converge(occupied_threshold, Layout::count_line_of_sight);
 
-    Ok(layout.occupied_seats())
+    layout.occupied_seats()
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -38,45 +46,43 @@ enum Cell {
     OccupiedSeat,
 }
 
-type Grid = Vec<Vec<Cell>>;
+impl TryFrom<char> for Cell {
+    type Error = anyhow::Error;
 
-struct Layout {
-    grid: Grid,
-    height: usize,
-    width: usize,
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '.' => Ok(Cell::Floor),
+            'L' => Ok(Cell::EmptySeat),
+            '#' => Ok(Cell::OccupiedSeat),
+            _ => Err(anyhow!("unknown char `{}`", c)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Layout {
+    grid: Grid<Cell>,
 }
 
 impl Layout {
     /// Steps one round in the simulation, returns the previous grid
-    fn step(
-        &mut self,
-        occupied_threshold: u8,
-        adj_count: fn(&Self, usize, usize, Cell) -> u8,
-    ) -> bool {
-        let grid = &self.grid;
-
-        let mut new = grid.clone();
+    fn step(&mut self, occupied_threshold: u8, adj_count: fn(&Self, Point, Cell) -> u8) -> bool {
+        let mut new = self.grid.clone();
         let mut changed = false;
 
-        for i in 0..self.height {
-            for j in 0..self.width {
-                let cell = self[i][j];
-
-                match cell {
-                    Cell::EmptySeat => {
-                        if adj_count(self, i, j, Cell::OccupiedSeat) == 0 {
-                            new[i][j] = Cell::OccupiedSeat;
-                            changed = true;
-                        }
-                    }
-                    Cell::OccupiedSeat => {
-                        if adj_count(self, i, j, Cell::OccupiedSeat) >= occupied_threshold {
-                            new[i][j] = Cell::EmptySeat;
-                            changed = true;
-                        }
-                    }
-                    _ => {}
+        for (point, &cell) in self.grid.iter() {
+            match cell {
+                Cell::EmptySeat if adj_count(self, point, Cell::OccupiedSeat) == 0 => {
+                    new[point] = Cell::OccupiedSeat;
+                    changed = true;
+                }
+                Cell::OccupiedSeat
+                    if adj_count(self, point, Cell::OccupiedSeat) >= occupied_threshold =>
+                {
+                    new[point] = Cell::EmptySeat;
+                    changed = true;
                 }
+                _ => {}
             }
         }
 
@@ -86,92 +92,37 @@ impl Layout {
     }
 
     /// Steps through the simulation until a fixpoint is reached
-    fn converge(&mut self, occupied_threshold: u8, adj_count: fn(&Self, usize, usize, Cell) -> u8) {
+    fn converge(&mut self, occupied_threshold: u8, adj_count: fn(&Self, Point, Cell) -> u8) {
         while self.step(occupied_threshold, adj_count) {}
     }
 
-    const OFFSETS: &'static [(i8, i8)] = &[
-        (0, -1),
-        (0, 1),
-        (-1, 0),
-        (1, 0),
-        (-1, -1),
-        (-1, 1),
-        (1, -1),
-        (1, 1),
-    ];
-
-    fn count_adjacent(&self, i: usize, j: usize, value: Cell) -> u8 {
-        let mut count = 0;
-
-        for (di, dj) in Self::OFFSETS {
-            let (i, j) = (i.wrapping_add(*di as usize), j.wrapping_add(*dj as usize));
-
-            // only check seat if it's in bounds
-            count += self
-                .grid
-                .get(i)
-                .and_then(|line| line.get(j))
-                .map(|&cell| u8::from(cell == value))
-                .unwrap_or(0);
-        }
-
-        count
+    fn count_adjacent(&self, point: Point, value: Cell) -> u8 {
+        self.grid
+            .neighbours8(point)
+            .filter(|&p| self.grid[p] == value)
+            .count() as u8
     }
 
-    fn count_line_of_sight(&self, i: usize, j: usize, value: Cell) -> u8 {
-        let mut count = 0;
-
-        for (di, dj) in Self::OFFSETS {
-            let mut distance = 1;
-
-            let diff = loop {
-                let (di, dj) = (di * distance, dj * distance);
-
-                let (i, j) = (i.wrapping_add(di as usize), j.wrapping_add(dj as usize));
-
-                let cell = self.grid.get(i).and_then(|line| line.get(j));
-
-                match cell {
-                    // keep going, the next seat is farther away
-                    Some(Cell::Floor) => distance += 1,
-                    // found the kind of seat we care about
-                    Some(&seat) if seat == value => break 1,
-                    // found a seat that blocks line of sight, or reached out of bounds
-                    Some(_) | None => break 0,
-                }
-            };
-
-            // only check seat if it's in bounds
-            count += diff;
-        }
-
-        count
+    fn count_line_of_sight(&self, point: Point, value: Cell) -> u8 {
+        DIRECTIONS8
+            .iter()
+            .filter(|&&direction| {
+                // floor doesn't block the view, the first seat does
+                self.grid
+                    .ray(point, direction)
+                    .map(|p| self.grid[p])
+                    .find(|&cell| cell != Cell::Floor)
+                    == Some(value)
+            })
+            .count() as u8
     }
 
     fn occupied_seats(&self) -> usize {
         self.grid
+            .cells()
             .iter()
-            .map(|line| {
-                line.iter()
-                    .filter(|seat| **seat == Cell::OccupiedSeat)
-                    .count()
-            })
-            .sum()
-    }
-}
-
-impl std::ops::Index<usize> for Layout {
-    type Output = Vec<Cell>;
-
-    fn index(&self, idx: usize) -> &Self::Output {
-        &self.grid[idx]
-    }
-}
-
-impl std::ops::IndexMut<usize> for Layout {
-    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
-        &mut self.grid[idx]
+            .filter(|&&seat| seat == Cell::OccupiedSeat)
+            .count()
     }
 }
 
@@ -179,28 +130,7 @@ impl std::str::FromStr for Layout {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let grid = s
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| match c {
-                        '.' => Ok(Cell::Floor),
-                        'L' => Ok(Cell::EmptySeat),
-                        '#' => Ok(Cell::OccupiedSeat),
-                        _ => Err(anyhow!("unknown char `{}`", c)),
-                    })
-                    .collect()
-            })
-            .collect::<Result<Grid>>()?;
-
-        let height = grid.len();
-        let width = grid[0].len();
-
-        Ok(Self {
-            grid,
-            height,
-            width,
-        })
+        Ok(Self { grid: s.parse()? })
     }
 }
 
@@ -212,23 +142,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 37);
-    }
-
-    #[test]
-    #[ignore]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 2427);
+        assert_eq!(part1(&PROVIDED.parse().unwrap()), 37);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 26);
-    }
-
-    #[test]
-    #[ignore]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2199);
+        assert_eq!(part2(&PROVIDED.parse().unwrap()), 26);
     }
 }
@@ -1,40 +1,43 @@
-use std::fmt::Write;
-
 use anyhow::{bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day12.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day12;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day12 {
+    type Input = Vec<Action>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
 
-fn part1(input: &str) -> Result<i64> {
-    let actions: Vec<Action> = input.lines().map(str::parse).collect::<Result<_>>()?;
+    fn part1(actions: &Self::Input) -> Result<Answer> {
+        Ok(part1(actions).into())
+    }
+
+    fn part2(actions: &Self::Input) -> Result<Answer> {
+        Ok(part2(actions).into())
+    }
+}
 
+fn part1(actions: &[Action]) -> i64 {
     let mut ship = Ship::new();
 
     for a in actions {
-        ship.process(&a);
+        ship.process(a);
     }
 
-    Ok(ship.manhattan_distance())
+    ship.manhattan_distance()
 }
 
-fn part2(input: &str) -> Result<i64> {
-    let actions: Vec<Action> = input.lines().map(str::parse).collect::<Result<_>>()?;
-
+fn part2(actions: &[Action]) -> i64 {
     let mut ship = Ship::new();
 
     for a in actions {
-        ship.process_with_waypoint(&a);
+        ship.process_with_waypoint(a);
     }
 
-    Ok(ship.manhattan_distance())
+    ship.manhattan_distance()
 }
 
 fn get_quadrants(degrees: i64) -> usize {
@@ -106,7 +109,7 @@ enum ActionKind {
 }
 
 #[derive(Debug, Clone)]
-struct Action {
+pub struct Action {
     kind: ActionKind,
     arg: i64,
 }
@@ -280,21 +283,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 25);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1589);
+        assert_eq!(part1(&Day12::parse(PROVIDED).unwrap()), 25);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 286);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 23960);
+        assert_eq!(part2(&Day12::parse(PROVIDED).unwrap()), 286);
     }
 }
@@ -1,97 +1,97 @@
-use std::fmt::Write;
-
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day13.txt");
+use aoc::math::crt;
+use aoc::{Answer, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(notes: &Self::Input) -> Result<Answer> {
+        Ok(part1(notes)?.into())
+    }
+
+    fn part2(notes: &Self::Input) -> Result<Answer> {
+        Ok(part2(notes)?.into())
+    }
+}
+
+pub struct Notes {
+    earliest_timestamp: u64,
+    /// Out of service buses are `None`.
+    buses: Vec<Option<u64>>,
+}
+
+impl std::str::FromStr for Notes {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines();
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+        let earliest_timestamp = lines
+            .next()
+            .context("input was empty")?
+            .parse::<u64>()
+            .context("couldn't parse first line")?;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+        let buses = parse_buses(lines.next().context("no second line")?)?;
 
-    Ok(res)
+        Ok(Notes {
+            earliest_timestamp,
+            buses,
+        })
+    }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let mut lines = input.lines();
-
-    let earliest_timestamp = lines
-        .next()
-        .context("input was empty")?
-        .parse::<u64>()
-        .context("couldn't parse first line")?;
-
-    let bus_ids = lines
-        .next()
-        .context("no second line")?
-        .split(',')
-        .filter_map(|num| {
-            if num == "x" {
-                None
-            } else {
-                Some(num.parse::<u64>().map_err(anyhow::Error::new))
-            }
+fn parse_buses(line: &str) -> Result<Vec<Option<u64>>> {
+    line.split(',')
+        .map(|num| match num {
+            "x" => Ok(None),
+            _ => num
+                .parse()
+                .map(Some)
+                .with_context(|| format!("couldn't parse bus id `{}'", num)),
         })
-        .collect::<Result<Vec<_>>>()?;
+        .collect()
+}
+
+fn part1(notes: &Notes) -> Result<u64> {
+    let earliest_timestamp = notes.earliest_timestamp;
 
-    let (bus_id, earliest_departure) = bus_ids
+    let (bus_id, earliest_departure) = notes
+        .buses
         .iter()
+        .flatten()
         .map(|id| {
             let next_departure = ((earliest_timestamp / id) * id) + id;
             (id, next_departure)
         })
         .min_by_key(|(_, next_departure)| *next_departure)
-        .unwrap();
+        .context("all the buses are out of service")?;
 
     Ok(bus_id * (earliest_departure - earliest_timestamp))
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let mut lines = input.lines();
-
-    // we don't need the first line anymore, skip it
-    lines.next().context("input was empty")?;
-
-    find_timestamp(lines.next().context("no second line")?)
+fn part2(notes: &Notes) -> Result<u64> {
+    find_timestamp(&notes.buses)
 }
 
-fn find_timestamp(input: &str) -> Result<u64> {
-    let bus_ids: Vec<(u64, u64)> = input
-        .split(',')
+fn find_timestamp(buses: &[Option<u64>]) -> Result<u64> {
+    // bus `id` leaving `idx` minutes after t means t ≡ -idx (mod id)
+    let congruences: Vec<(i64, i64)> = buses
+        .iter()
         .enumerate()
-        .filter_map(|(idx, num)| {
-            if num == "x" {
-                None
-            } else {
-                Some((idx as u64, num.parse::<u64>().map_err(anyhow::Error::new)))
-            }
-        })
-        .map(|(idx, res)| match res {
-            Ok(num) => Ok((idx, num)),
-            Err(e) => Err(e),
-        })
-        .collect::<Result<_>>()?;
-
-    // previous constraints is empty for now
-    let mut current_solution = 0;
-    let mut step = 1;
-
-    for constraint in bus_ids {
-        while !satisfies_constraint(current_solution, constraint) {
-            current_solution += step;
-        }
-
-        let (_, divisor) = constraint;
+        .filter_map(|(idx, id)| id.map(|id| (-(idx as i64), id as i64)))
+        .collect();
 
-        step *= divisor;
-    }
-
-    Ok(current_solution)
-}
+    let (timestamp, _) = crt(&congruences).context("no timestamp satisfies all the buses")?;
 
-fn satisfies_constraint(solution: u64, (remainder, divisor): (u64, u64)) -> bool {
-    (solution + remainder).is_multiple_of(divisor)
+    Ok(timestamp as u64)
 }
 
 #[cfg(test)]
@@ -102,12 +102,7 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 295);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 3269);
+        assert_eq!(part1(&PROVIDED.parse().unwrap()).unwrap(), 295);
     }
 
     #[test]
@@ -121,17 +116,15 @@ mod tests {
         ];
 
         for &(input, expected) in tests {
-            assert_eq!(find_timestamp(input).unwrap(), expected);
+            assert_eq!(
+                find_timestamp(&parse_buses(input).unwrap()).unwrap(),
+                expected
+            );
         }
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 1068781);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 672754131923874);
+        assert_eq!(part2(&PROVIDED.parse().unwrap()).unwrap(), 1068781);
     }
 }
@@ -1,29 +1,37 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 
 use anyhow::{anyhow, bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day14.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day14;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day14 {
+    type Input = Program;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(program: &Self::Input) -> Result<Answer> {
+        Ok(part1(program)?.into())
+    }
+
+    fn part2(program: &Self::Input) -> Result<Answer> {
+        Ok(part2(program)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let mut program: Program = input.parse()?;
+fn part1(program: &Program) -> Result<u64> {
+    let mut program = program.clone();
 
     program.run_part1()?;
 
     Ok(program.memory_sum())
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let mut program: Program = input.parse()?;
+fn part2(program: &Program) -> Result<u64> {
+    let mut program = program.clone();
 
     program.run_part2()?;
 
@@ -196,7 +204,7 @@ impl std::str::FromStr for BitMask {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Instruction {
     MemWrite { offset: usize, value: u64 },
     ChangeMask(BitMask),
@@ -237,7 +245,8 @@ impl std::str::FromStr for Instruction {
     }
 }
 
-struct Program {
+#[derive(Clone)]
+pub struct Program {
     instructions: Vec<Instruction>,
     memory: HashMap<usize, u64>,
     current_mask: Option<BitMask>,
@@ -313,21 +322,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 165);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 4297467072083);
+        assert_eq!(part1(&PROVIDED1.parse().unwrap()).unwrap(), 165);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED2).unwrap(), 208);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 5030603328768);
+        assert_eq!(part2(&PROVIDED2.parse().unwrap()).unwrap(), 208);
     }
 }
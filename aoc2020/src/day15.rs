@@ -1,38 +1,38 @@
-use std::fmt::Write;
-
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day15.txt");
+use aoc::{Answer, Solution};
+
 const PART1_TURNS: usize = 2020;
 const PART2_TURNS: usize = 30_000_000;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day15;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day15 {
+    type Input = Vec<u64>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .trim_end()
+            .split(',')
+            .map(|num| Ok(num.parse()?))
+            .collect()
+    }
 
-fn part1(input: &str) -> Result<u64> {
-    let starting_numbers = input
-        .trim_end()
-        .split(',')
-        .map(|num| num.parse().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<u64>>>()?;
+    fn part1(starting_numbers: &Self::Input) -> Result<Answer> {
+        Ok(part1(starting_numbers).into())
+    }
 
-    Ok(get_last_spoken_number(&starting_numbers, PART1_TURNS))
+    fn part2(starting_numbers: &Self::Input) -> Result<Answer> {
+        Ok(part2(starting_numbers).into())
+    }
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let starting_numbers = input
-        .trim_end()
-        .split(',')
-        .map(|num| num.parse().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<u64>>>()?;
+fn part1(starting_numbers: &[u64]) -> u64 {
+    get_last_spoken_number(starting_numbers, PART1_TURNS)
+}
 
-    Ok(get_last_spoken_number(&starting_numbers, PART2_TURNS))
+fn part2(starting_numbers: &[u64]) -> u64 {
+    get_last_spoken_number(starting_numbers, PART2_TURNS)
 }
 
 fn get_last_spoken_number(starting_numbers: &[u64], game_length: usize) -> u64 {
@@ -94,28 +94,17 @@ mod tests {
         let expected = [436, 1, 10, 27, 78, 438, 1836];
 
         for (line, expected) in PROVIDED.lines().zip(expected.iter()) {
-            assert_eq!(part1(line).unwrap(), *expected);
+            assert_eq!(part1(&Day15::parse(line).unwrap()), *expected);
         }
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 447);
-    }
-
     #[test]
     #[ignore]
     fn part2_provided() {
         let expected = [175594, 2578, 3544142, 261214, 6895259, 18, 362];
 
         for (line, expected) in PROVIDED.lines().zip(expected.iter()) {
-            assert_eq!(part2(line).unwrap(), *expected);
+            assert_eq!(part2(&Day15::parse(line).unwrap()), *expected);
         }
     }
-
-    #[test]
-    #[ignore]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 11721679);
-    }
 }
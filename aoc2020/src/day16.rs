@@ -1,39 +1,46 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 use std::ops::RangeInclusive;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day16.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day16;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day16 {
+    type Input = Notes;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
 
-fn part1(input: &str) -> Result<u64> {
-    let (fields, _, tickets) = parse_input(input)?;
+    fn part1(notes: &Self::Input) -> Result<Answer> {
+        Ok(part1(notes).into())
+    }
 
-    let fields_vec = fields.values().collect::<Vec<_>>();
+    fn part2(notes: &Self::Input) -> Result<Answer> {
+        Ok(part2(notes).into())
+    }
+}
 
-    Ok(tickets
+fn part1(notes: &Notes) -> u64 {
+    let fields_vec = notes.fields.values().collect::<Vec<_>>();
+
+    notes
+        .tickets
         .iter()
         .flat_map(|t| t.invalid_values(&fields_vec))
-        .sum())
+        .sum()
 }
 
-fn assign_field_positions(
-    fields: HashMap<&str, Field>,
-    tickets: Vec<Ticket>,
-) -> HashMap<usize, &str> {
+fn assign_field_positions<'a>(
+    fields: &'a HashMap<String, Field>,
+    tickets: &[Ticket],
+) -> HashMap<usize, &'a str> {
     let fields_vec = fields.values().collect::<Vec<_>>();
 
-    let tickets: Vec<Ticket> = tickets
-        .into_iter()
+    let tickets: Vec<&Ticket> = tickets
+        .iter()
         .filter(|t| t.invalid_values(&fields_vec).count() == 0)
         .collect();
 
@@ -42,16 +49,16 @@ fn assign_field_positions(
     // build list of all possibilities for each field
     let mut possibilities = fields
         .iter()
-        .map(|(&name, field)| {
+        .map(|(name, field)| {
             let possibilities = (0..num_values)
                 .filter(|i| tickets.iter().all(|t| t.valid_field(field, *i)))
                 .collect();
 
-            (name, possibilities)
+            (name.as_str(), possibilities)
         })
         .collect::<HashMap<&str, Vec<usize>>>();
 
-    let mut fields_to_assign: Vec<&str> = fields.keys().copied().collect();
+    let mut fields_to_assign: Vec<&str> = fields.keys().map(String::as_str).collect();
     let mut field_indices: HashMap<usize, &str> = HashMap::new();
 
     for _ in 0..fields.len() {
@@ -80,12 +87,11 @@ fn assign_field_positions(
     field_indices
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let (fields, my_ticket, tickets) = parse_input(input)?;
+fn part2(notes: &Notes) -> u64 {
+    let field_pos_matches = assign_field_positions(&notes.fields, &notes.tickets);
 
-    let field_pos_matches = assign_field_positions(fields, tickets);
-
-    Ok(my_ticket
+    notes
+        .my_ticket
         .values
         .iter()
         .enumerate()
@@ -94,41 +100,55 @@ fn part2(input: &str) -> Result<u64> {
             field.starts_with("departure")
         })
         .map(|(_, val)| val)
-        .product())
+        .product()
 }
 
-fn parse_input(input: &str) -> Result<(HashMap<&str, Field>, Ticket, Vec<Ticket>)> {
-    let mut parts = input.split("\n\n");
+pub struct Notes {
+    fields: HashMap<String, Field>,
+    my_ticket: Ticket,
+    tickets: Vec<Ticket>,
+}
 
-    let fields_part = parts.next().context("no fields specification found")?;
-    let my_ticket_part = parts.next().context("no personal ticket found")?;
-    let tickets_part = parts.next().context("no list of tickets found")?;
+impl std::str::FromStr for Notes {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut parts = input.split("\n\n");
+
+        let fields_part = parts.next().context("no fields specification found")?;
+        let my_ticket_part = parts.next().context("no personal ticket found")?;
+        let tickets_part = parts.next().context("no list of tickets found")?;
 
-    let fields = fields_part
-        .lines()
-        .map(|line| {
-            let mut parts = line.split(": ");
-            let name = parts.next().context("no name found")?;
-            let field = parts.next().context("no ranges found")?.parse()?;
+        let fields = fields_part
+            .lines()
+            .map(|line| {
+                let mut parts = line.split(": ");
+                let name = parts.next().context("no name found")?;
+                let field = parts.next().context("no ranges found")?.parse()?;
 
-            Ok((name, field))
+                Ok((name.to_string(), field))
+            })
+            .collect::<Result<_>>()
+            .context("couldn't parse fields")?;
+        let my_ticket = my_ticket_part
+            .lines()
+            .skip(1)
+            .map(str::parse)
+            .next()
+            .context("no second line for ticket")??;
+        let tickets = tickets_part
+            .lines()
+            .skip(1)
+            .map(str::parse)
+            .collect::<Result<_>>()
+            .context("couldn't parse tickets")?;
+
+        Ok(Notes {
+            fields,
+            my_ticket,
+            tickets,
         })
-        .collect::<Result<_>>()
-        .context("couldn't parse fields")?;
-    let my_ticket = my_ticket_part
-        .lines()
-        .skip(1)
-        .map(str::parse)
-        .next()
-        .context("no second line for ticket")??;
-    let tickets = tickets_part
-        .lines()
-        .skip(1)
-        .map(str::parse)
-        .collect::<Result<_>>()
-        .context("couldn't parse tickets")?;
-
-    Ok((fields, my_ticket, tickets))
+    }
 }
 
 #[derive(Debug)]
@@ -208,19 +228,14 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 71);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 20013);
+        assert_eq!(part1(&PROVIDED1.parse().unwrap()), 71);
     }
 
     #[test]
     fn part2_provided() {
-        let (fields, _, tickets) = parse_input(PROVIDED2).unwrap();
+        let notes: Notes = PROVIDED2.parse().unwrap();
 
-        let matches = assign_field_positions(fields, tickets);
+        let matches = assign_field_positions(&notes.fields, &notes.tickets);
 
         let expected = ["row", "class", "seat"]
             .iter()
@@ -230,9 +245,4 @@ mod tests {
 
         assert_eq!(matches, expected);
     }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 5977293343129);
-    }
 }
@@ -1,39 +1,61 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::{anyhow, Result};
 
-use itertools::iproduct;
+use aoc::combinatorics::product;
+use aoc::{Answer, Solution};
 
-const INPUT: &str = include_str!("../input/day17.txt");
+pub struct Day17;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+impl Solution for Day17 {
+    type Input = Vec<(i64, i64)>;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_slice(input)
+    }
+
+    fn part1(slice: &Self::Input) -> Result<Answer> {
+        Ok(part1(slice).into())
+    }
+
+    fn part2(slice: &Self::Input) -> Result<Answer> {
+        Ok(part2(slice).into())
+    }
+}
 
-    Ok(res)
+/// The active cubes of the initial 2D slice.
+fn parse_slice(input: &str) -> Result<Vec<(i64, i64)>> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(i, line)| {
+            line.chars().enumerate().filter_map(move |(j, c)| match c {
+                '#' => Some(Ok((i as i64, j as i64))),
+                '.' => None,
+                _ => Some(Err(anyhow!("unexpected char: `{}`", c))),
+            })
+        })
+        .collect()
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let mut pocket_dim: PocketDimension = input.parse()?;
+fn part1(slice: &[(i64, i64)]) -> usize {
+    let mut pocket_dim = PocketDimension::new(slice);
 
     for _ in 0..6 {
         pocket_dim.update();
     }
 
-    Ok(pocket_dim.points.len())
+    pocket_dim.points.len()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut pocket_dim: PocketDimension4D = input.parse()?;
+fn part2(slice: &[(i64, i64)]) -> usize {
+    let mut pocket_dim = PocketDimension4D::new(slice);
 
     for _ in 0..6 {
         pocket_dim.update();
     }
 
-    Ok(pocket_dim.points.len())
+    pocket_dim.points.len()
 }
 
 type Point = (i64, i64, i64);
@@ -43,10 +65,16 @@ struct PocketDimension {
 }
 
 impl PocketDimension {
+    fn new(slice: &[(i64, i64)]) -> Self {
+        let points = slice.iter().map(|&(i, j)| (i, j, 0)).collect();
+
+        Self { points }
+    }
+
     fn neighbours(point: Point) -> impl Iterator<Item = Point> {
-        iproduct!(-1..=1, -1..=1, -1..=1)
-            .filter(|(x, y, z)| *x != 0 || *y != 0 || *z != 0)
-            .map(move |(dx, dy, dz)| (point.0 + dx, point.1 + dy, point.2 + dz))
+        product([-1..=1, -1..=1, -1..=1])
+            .filter(|&delta| delta != [0; 3])
+            .map(move |[dx, dy, dz]| (point.0 + dx, point.1 + dy, point.2 + dz))
     }
 
     fn active_neighbours(&self, point: Point) -> usize {
@@ -83,26 +111,6 @@ impl PocketDimension {
     }
 }
 
-impl std::str::FromStr for PocketDimension {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let points = s
-            .lines()
-            .enumerate()
-            .flat_map(|(i, line)| {
-                line.chars().enumerate().filter_map(move |(j, c)| match c {
-                    '#' => Some(Ok((i as i64, j as i64, 0))),
-                    '.' => None,
-                    _ => Some(Err(anyhow!("unexpected char: `{}`", c))),
-                })
-            })
-            .collect::<Result<_>>()?;
-
-        Ok(Self { points })
-    }
-}
-
 // TODO: see how much of this can be factorized once min_const_generics is stabilized
 type Point4D = (i64, i64, i64, i64);
 
@@ -111,10 +119,16 @@ struct PocketDimension4D {
 }
 
 impl PocketDimension4D {
+    fn new(slice: &[(i64, i64)]) -> Self {
+        let points = slice.iter().map(|&(i, j)| (i, j, 0, 0)).collect();
+
+        Self { points }
+    }
+
     fn neighbours(point: Point4D) -> impl Iterator<Item = Point4D> {
-        iproduct!(-1..=1, -1..=1, -1..=1, -1..=1)
-            .filter(|(x, y, z, w)| *x != 0 || *y != 0 || *z != 0 || *w != 0)
-            .map(move |(dx, dy, dz, dw)| (point.0 + dx, point.1 + dy, point.2 + dz, point.3 + dw))
+        product([-1..=1, -1..=1, -1..=1, -1..=1])
+            .filter(|&delta| delta != [0; 4])
+            .map(move |[dx, dy, dz, dw]| (point.0 + dx, point.1 + dy, point.2 + dz, point.3 + dw))
     }
 
     fn active_neighbours(&self, point: Point4D) -> usize {
@@ -151,26 +165,6 @@ impl PocketDimension4D {
     }
 }
 
-impl std::str::FromStr for PocketDimension4D {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let points = s
-            .lines()
-            .enumerate()
-            .flat_map(|(i, line)| {
-                line.chars().enumerate().filter_map(move |(j, c)| match c {
-                    '#' => Some(Ok((i as i64, j as i64, 0, 0))),
-                    '.' => None,
-                    _ => Some(Err(anyhow!("unexpected char: `{}`", c))),
-                })
-            })
-            .collect::<Result<_>>()?;
-
-        Ok(Self { points })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,23 +173,12 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 112);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 336);
+        assert_eq!(part1(&parse_slice(PROVIDED).unwrap()), 112);
     }
 
     #[test]
     #[ignore]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 848);
-    }
-
-    #[test]
-    #[ignore]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2620);
+        assert_eq!(part2(&parse_slice(PROVIDED).unwrap()), 848);
     }
 }
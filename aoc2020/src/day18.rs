@@ -1,7 +1,7 @@
-use std::fmt::Write;
-
 use anyhow::{anyhow, Result};
 
+use aoc::{Answer, Solution};
+
 use nom::{
     branch::alt,
     bytes::complete::take_while1,
@@ -12,37 +12,57 @@ use nom::{
     IResult, Parser,
 };
 
-const INPUT: &str = include_str!("../input/day18.txt");
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Homework;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+    fn part1(homework: &Self::Input) -> Result<Answer> {
+        Ok(part1(homework).into())
+    }
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn part2(homework: &Self::Input) -> Result<Answer> {
+        Ok(part2(homework).into())
+    }
+}
 
-    Ok(res)
+fn part1(homework: &Homework) -> u64 {
+    homework.part1.iter().map(Expr::eval).sum()
 }
 
-fn part1(input: &str) -> Result<u64> {
-    input
-        .lines()
-        .map(|line| {
-            terminated(expr, eof)(line)
-                .map_err(|_| anyhow!("couldn't parse expr"))
-                .map(|(_, e)| e.eval())
-        })
-        .sum()
+fn part2(homework: &Homework) -> u64 {
+    homework.part2.iter().map(Expr::eval).sum()
+}
+
+/// Operator precedence differs in each part, so each line is parsed twice.
+pub struct Homework {
+    part1: Vec<Expr>,
+    part2: Vec<Expr>,
 }
 
-fn part2(input: &str) -> Result<u64> {
-    input
-        .lines()
-        .map(|line| {
-            terminated(plus_priority, eof)(line)
-                .map_err(|_| anyhow!("couldn't parse expr"))
-                .map(|(_, e)| e.eval())
+impl std::str::FromStr for Homework {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let parse_all = |parser: fn(&str) -> IResult<&str, Expr>| {
+            s.lines()
+                .map(|line| {
+                    terminated(parser, eof)(line)
+                        .map_err(|_| anyhow!("couldn't parse expr"))
+                        .map(|(_, e)| e)
+                })
+                .collect::<Result<_>>()
+        };
+
+        Ok(Homework {
+            part1: parse_all(expr)?,
+            part2: parse_all(plus_priority)?,
         })
-        .sum()
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -163,11 +183,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 800602729153);
-    }
-
     #[test]
     fn part2_provided() {
         let tests = &[
@@ -184,9 +199,4 @@ mod tests {
             assert_eq!(exp.eval(), *expected);
         }
     }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 92173009047076);
-    }
 }
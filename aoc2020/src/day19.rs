@@ -1,17 +1,25 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day19.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day19;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day19 {
+    type Input = Messages;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(messages: &Self::Input) -> Result<Answer> {
+        Ok(part1(messages).into())
+    }
+
+    fn part2(messages: &Self::Input) -> Result<Answer> {
+        Ok(part2(messages).into())
+    }
 }
 
 fn get_rules(input: &str) -> Result<HashMap<usize, Rule>> {
@@ -35,24 +43,37 @@ fn get_rules(input: &str) -> Result<HashMap<usize, Rule>> {
         .collect()
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let mut parts = input.split("\n\n");
+pub struct Messages {
+    rules: HashMap<usize, Rule>,
+    lines: Vec<String>,
+}
+
+impl std::str::FromStr for Messages {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split("\n\n");
 
-    let rules = parts.next().context("no rules before linebreak")?;
-    let rules = get_rules(rules)?;
+        let rules = parts.next().context("no rules before linebreak")?;
+        let rules = get_rules(rules)?;
 
-    let lines = parts.next().context("no lines after linebreak")?.lines();
+        let lines = parts.next().context("no lines after linebreak")?.lines();
+        let lines = lines.map(str::to_string).collect();
 
-    Ok(lines.filter(|l| rules[&0].matches(&rules, l)).count())
+        Ok(Messages { rules, lines })
+    }
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut parts = input.split("\n\n");
+fn count_matching(rules: &HashMap<usize, Rule>, lines: &[String]) -> usize {
+    lines.iter().filter(|l| rules[&0].matches(rules, l)).count()
+}
 
-    let rules = parts.next().context("no rules before linebreak")?;
-    let mut rules = get_rules(rules)?;
+fn part1(messages: &Messages) -> usize {
+    count_matching(&messages.rules, &messages.lines)
+}
 
-    let lines = parts.next().context("no lines after linebreak")?.lines();
+fn part2(messages: &Messages) -> usize {
+    let mut rules = messages.rules.clone();
 
     rules.insert(
         8,
@@ -69,10 +90,10 @@ fn part2(input: &str) -> Result<usize> {
         ),
     );
 
-    Ok(lines.filter(|l| rules[&0].matches(&rules, l)).count())
+    count_matching(&rules, &messages.lines)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Rule {
     Character(char),
     Chain(Vec<usize>),
@@ -188,18 +209,8 @@ mod tests {
         }
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 144);
-    }
-
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED2).unwrap(), 12);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 260);
+        assert_eq!(part2(&PROVIDED2.parse().unwrap()), 12);
     }
 }
@@ -1,28 +1,34 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 
 use anyhow::{anyhow, Context, Result};
 
-const INPUT: &str = include_str!("../input/day20.txt");
+use aoc::{Answer, Solution};
 
 const SNAKE: &str = include_str!("../input/day20_snake.txt");
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day20;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day20 {
+    type Input = Vec<Tile>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.split("\n\n").map(str::parse).collect()
+    }
 
-fn part1(input: &str) -> Result<u64> {
-    let tiles: Vec<Tile> = input.split("\n\n").map(str::parse).collect::<Result<_>>()?;
+    fn part1(tiles: &Self::Input) -> Result<Answer> {
+        Ok(part1(tiles).into())
+    }
 
-    Ok(tiles
+    fn part2(tiles: &Self::Input) -> Result<Answer> {
+        Ok(part2(tiles)?.into())
+    }
+}
+
+fn part1(tiles: &[Tile]) -> u64 {
+    tiles
         .iter()
         .filter_map(|tile| {
-            let count = tile.neighbours(&tiles).len();
+            let count = tile.neighbours(tiles).len();
 
             // corners have 2 edges in common
             if count == 2 {
@@ -31,13 +37,11 @@ fn part1(input: &str) -> Result<u64> {
                 None
             }
         })
-        .product())
+        .product()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let tiles: Vec<Tile> = input.split("\n\n").map(str::parse).collect::<Result<_>>()?;
-
-    let image = Image::from_tiles(&tiles);
+fn part2(tiles: &[Tile]) -> Result<usize> {
+    let image = Image::from_tiles(tiles);
     let snake: Pattern = SNAKE.parse()?;
 
     let snake_number = image.count_pattern(&snake);
@@ -162,7 +166,7 @@ const TILE_WIDTH: usize = 10;
 const TILE_HEIGHT: usize = 10;
 
 #[derive(Debug, Clone)]
-struct Tile {
+pub struct Tile {
     id: u64,
     cells: [[bool; TILE_WIDTH]; TILE_HEIGHT],
     transform: Transform,
@@ -476,21 +480,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 20_899_048_083_289);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 5_775_714_912_743);
+        assert_eq!(part1(&Day20::parse(PROVIDED).unwrap()), 20_899_048_083_289);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 273);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 1836);
+        assert_eq!(part2(&Day20::parse(PROVIDED).unwrap()).unwrap(), 273);
     }
 }
@@ -1,47 +1,82 @@
 use std::collections::{HashMap, HashSet};
-use std::convert::TryInto;
-use std::fmt::Write;
+use std::str::FromStr;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day21.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day21;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day21 {
+    type Input = Vec<Food>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(foods: &Self::Input) -> Result<Answer> {
+        Ok(part1(foods).into())
+    }
+
+    fn part2(foods: &Self::Input) -> Result<Answer> {
+        Ok(part2(foods).into())
+    }
 }
 
-/// Returns all words, and the number of times they appear in the input (useful for part1)
-fn get_all_words(input: &str) -> HashMap<&str, usize> {
-    input.lines().fold(HashMap::new(), |mut map, line| {
-        let left_paren = line.find('(').unwrap();
+pub struct Food {
+    ingredients: Vec<String>,
+    allergens: Vec<String>,
+}
+
+impl FromStr for Food {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let left_paren = s.find('(').context("couldn't find open paren in line")?;
+        let right_paren = s.find(')').context("couldn't find closing paren in line")?;
+
+        let allergens = s[(left_paren + "contains ".len() + 1)..right_paren]
+            .split(", ")
+            .map(str::to_string)
+            .collect();
+
+        let ingredients = s[..(left_paren - 1)]
+            .split(' ')
+            .map(str::to_string)
+            .collect();
+
+        Ok(Food {
+            ingredients,
+            allergens,
+        })
+    }
+}
 
-        line[..(left_paren - 1)].split(' ').for_each(|word| {
-            *map.entry(word).or_default() += 1;
+/// Returns all words, and the number of times they appear in the input (useful for part1)
+fn get_all_words(foods: &[Food]) -> HashMap<&str, usize> {
+    foods.iter().fold(HashMap::new(), |mut map, food| {
+        food.ingredients.iter().for_each(|word| {
+            *map.entry(word.as_str()).or_default() += 1;
         });
 
         map
     })
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let matchings: AllergenMatchings = input.try_into()?;
-    let all_words = get_all_words(input);
+fn part1(foods: &[Food]) -> usize {
+    let matchings = AllergenMatchings::new(foods);
+    let all_words = get_all_words(foods);
 
     // identify words that aren't in any allergen possible matching list
     let not_allergens = all_words
         .keys()
         .filter(|&word| !matchings.0.values().any(|set| set.contains(word)));
 
-    Ok(not_allergens.map(|word| all_words[word]).sum())
+    not_allergens.map(|word| all_words[word]).sum()
 }
 
-fn part2(input: &str) -> Result<String> {
-    let mut matchings: AllergenMatchings = input.try_into()?;
+fn part2(foods: &[Food]) -> String {
+    let mut matchings = AllergenMatchings::new(foods);
     let mut allergens_to_identify: Vec<&str> = matchings.0.keys().copied().collect();
 
     for _ in 0..matchings.0.len() {
@@ -87,36 +122,29 @@ fn part2(input: &str) -> Result<String> {
         .map(|(_, translation)| *translation)
         .collect();
 
-    Ok(canonical_ingredient_list.join(","))
+    canonical_ingredient_list.join(",")
 }
 
 #[derive(Debug)]
 struct AllergenMatchings<'a>(HashMap<&'a str, HashSet<&'a str>>);
 
-impl<'a> std::convert::TryFrom<&'a str> for AllergenMatchings<'a> {
-    type Error = anyhow::Error;
-
-    fn try_from(s: &'a str) -> Result<Self> {
+impl<'a> AllergenMatchings<'a> {
+    fn new(foods: &'a [Food]) -> Self {
         let mut matchings = HashMap::new();
 
-        for line in s.lines() {
-            let left_paren = line.find('(').context("couldn't find open paren in line")?;
-            let right_paren = line
-                .find(')')
-                .context("couldn't find closing paren in line")?;
-
-            let allergens = line[(left_paren + "contains ".len() + 1)..right_paren].split(", ");
-
-            let words: HashSet<&str> = line[..(left_paren - 1)].split(' ').collect();
+        for food in foods {
+            let words: HashSet<&str> = food.ingredients.iter().map(String::as_str).collect();
 
             // update potential matches for each allergen mentioned in line
-            for allergen in allergens {
-                let set = matchings.entry(allergen).or_insert_with(|| words.clone());
+            for allergen in &food.allergens {
+                let set = matchings
+                    .entry(allergen.as_str())
+                    .or_insert_with(|| words.clone());
                 *set = set.intersection(&words).copied().collect();
             }
         }
 
-        Ok(AllergenMatchings(matchings))
+        AllergenMatchings(matchings)
     }
 }
 
@@ -128,24 +156,14 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 5);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 2315);
+        assert_eq!(part1(&Day21::parse(PROVIDED).unwrap()), 5);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), "mxmxvkd,sqjhc,fvjkl");
-    }
-
-    #[test]
-    fn part2_real() {
         assert_eq!(
-            part2(INPUT).unwrap(),
-            "cfzdnz,htxsjf,ttbrlvd,bbbl,lmds,cbmjz,cmbcm,dvnbh"
+            part2(&Day21::parse(PROVIDED).unwrap()),
+            "mxmxvkd,sqjhc,fvjkl"
         );
     }
 }
@@ -1,20 +1,28 @@
 use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashSet, VecDeque};
-use std::fmt::Write;
 use std::hash::{Hash, Hasher};
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day22.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day22;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day22 {
+    type Input = (Deck, Deck);
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_decks(input)
+    }
+
+    fn part1((deck_a, deck_b): &Self::Input) -> Result<Answer> {
+        Ok(part1(deck_a.clone(), deck_b.clone()).into())
+    }
+
+    fn part2((deck_a, deck_b): &Self::Input) -> Result<Answer> {
+        Ok(part2(deck_a.clone(), deck_b.clone()).into())
+    }
 }
 
 fn play_game(mut deck_a: Deck, mut deck_b: Deck) -> Deck {
@@ -125,30 +133,29 @@ fn deck_score(deck: &Deck) -> u64 {
         .sum()
 }
 
-fn part1(input: &str) -> Result<u64> {
+fn parse_decks(input: &str) -> Result<(Deck, Deck)> {
     let mut decks = input.split("\n\n");
 
-    let deck_a: Deck = decks.next().context("couldn't get first deck")?.parse()?;
-    let deck_b: Deck = decks.next().context("couldn't get second deck")?.parse()?;
-
-    let winning_deck = play_game(deck_a, deck_b);
+    let deck_a = decks.next().context("couldn't get first deck")?.parse()?;
+    let deck_b = decks.next().context("couldn't get second deck")?.parse()?;
 
-    Ok(deck_score(&winning_deck))
+    Ok((deck_a, deck_b))
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let mut decks = input.split("\n\n");
+fn part1(deck_a: Deck, deck_b: Deck) -> u64 {
+    let winning_deck = play_game(deck_a, deck_b);
 
-    let deck_a: Deck = decks.next().context("couldn't get first deck")?.parse()?;
-    let deck_b: Deck = decks.next().context("couldn't get second deck")?.parse()?;
+    deck_score(&winning_deck)
+}
 
+fn part2(deck_a: Deck, deck_b: Deck) -> u64 {
     let (winning_deck, _) = play_recursive_game(deck_a, deck_b);
 
-    Ok(deck_score(&winning_deck))
+    deck_score(&winning_deck)
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
-struct Deck(VecDeque<u64>);
+pub struct Deck(VecDeque<u64>);
 
 impl Deck {}
 
@@ -177,22 +184,13 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 306);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 30780);
+        let (deck_a, deck_b) = parse_decks(PROVIDED).unwrap();
+        assert_eq!(part1(deck_a, deck_b), 306);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 291);
-    }
-
-    #[test]
-    #[ignore]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 36621);
+        let (deck_a, deck_b) = parse_decks(PROVIDED).unwrap();
+        assert_eq!(part2(deck_a, deck_b), 291);
     }
 }
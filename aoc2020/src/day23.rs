@@ -1,33 +1,46 @@
-use std::{collections::VecDeque, fmt::Write};
+use std::collections::VecDeque;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day23.txt");
+use aoc::{Answer, Solution};
 
 const CUP_NUMBER: usize = 1_000_000;
 const TURNS_NUMBER: usize = 10_000_000;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day23;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day23 {
+    type Input = Vec<usize>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .trim_end()
+            .chars()
+            .map(|c| Ok(c.to_digit(10).context("character was not a digit")? as usize))
+            .collect()
+    }
+
+    fn part1(cups: &Self::Input) -> Result<Answer> {
+        Ok(part1(cups).into())
+    }
+
+    fn part2(cups: &Self::Input) -> Result<Answer> {
+        Ok(part2(cups)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<String> {
-    let mut cup_circle: CupCircle = input.parse()?;
+fn part1(cups: &[usize]) -> String {
+    let mut cup_circle = CupCircle::new(cups);
 
     for _ in 0..100 {
         cup_circle.step();
     }
 
-    Ok(format!("{}", cup_circle))
+    format!("{}", cup_circle)
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut cup_circle: FastCupCircle = input.parse()?;
+fn part2(cups: &[usize]) -> Result<usize> {
+    let mut cup_circle = FastCupCircle::new(cups)?;
 
     for _ in 0..TURNS_NUMBER {
         cup_circle.step();
@@ -48,6 +61,10 @@ struct CupCircle(VecDeque<u64>);
 /// The Circle should never become empty if you only use its public interface, so all calls to
 /// `unwrap()` in its implementation should never panic.
 impl CupCircle {
+    fn new(cups: &[usize]) -> Self {
+        CupCircle(cups.iter().map(|&cup| cup as u64).collect())
+    }
+
     /// Shifts the cup circle, putting the first cup at the end of the deque
     ///
     /// This doesn't change anything to the cup circle layout, except that the new first cup is
@@ -125,20 +142,6 @@ impl CupCircle {
     }
 }
 
-impl std::str::FromStr for CupCircle {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let circle = s
-            .trim_end()
-            .chars()
-            .map(|c| Ok(c.to_digit(10).context("character was not a digit")? as u64))
-            .collect::<Result<_>>()?;
-
-        Ok(CupCircle(circle))
-    }
-}
-
 impl std::fmt::Display for CupCircle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0
@@ -162,6 +165,29 @@ struct FastCupCircle {
 }
 
 impl FastCupCircle {
+    fn new(input_cups: &[usize]) -> Result<Self> {
+        let max = *input_cups.iter().max().context("input contained 0 cups")?;
+
+        let mut cups = Vec::new();
+        cups.resize_with(CUP_NUMBER, Default::default);
+
+        let cup_iter = input_cups.iter().copied().chain((max + 1)..=CUP_NUMBER);
+        let next_cup_iter = input_cups
+            .iter()
+            .copied()
+            .chain((max + 1)..=CUP_NUMBER)
+            .cycle()
+            .skip(1);
+
+        for (cup, next) in cup_iter.zip(next_cup_iter) {
+            cups[cup - 1] = next;
+        }
+
+        let current = cups[CUP_NUMBER - 1];
+
+        Ok(Self { cups, current })
+    }
+
     fn next_cup(&self, cup: usize) -> &usize {
         &self.cups[cup - 1]
     }
@@ -219,38 +245,6 @@ impl FastCupCircle {
     }
 }
 
-impl std::str::FromStr for FastCupCircle {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let input_cups: Vec<usize> = s
-            .trim_end()
-            .chars()
-            .map(|c| Ok(c.to_digit(10).context("character was not a digit")? as usize))
-            .collect::<Result<_>>()?;
-
-        let max = *input_cups.iter().max().context("input contained 0 cups")?;
-
-        let mut cups = Vec::new();
-        cups.resize_with(CUP_NUMBER, Default::default);
-
-        let cup_iter = input_cups.clone().into_iter().chain((max + 1)..=CUP_NUMBER);
-        let next_cup_iter = input_cups
-            .into_iter()
-            .chain((max + 1)..=CUP_NUMBER)
-            .cycle()
-            .skip(1);
-
-        for (cup, next) in cup_iter.zip(next_cup_iter) {
-            cups[cup - 1] = next;
-        }
-
-        let current = cups[CUP_NUMBER - 1];
-
-        Ok(Self { cups, current })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,23 +253,15 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), "67384529");
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), "72496583");
+        assert_eq!(part1(&Day23::parse(PROVIDED).unwrap()), "67384529");
     }
 
     #[test]
     #[ignore]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 149245887792);
-    }
-
-    #[test]
-    #[ignore]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 41785843847);
+        assert_eq!(
+            part2(&Day23::parse(PROVIDED).unwrap()).unwrap(),
+            149245887792
+        );
     }
 }
@@ -1,17 +1,25 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::{bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day24.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day24;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day24 {
+    type Input = HashSet<HexCoordinates>;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        compute_pattern(input)
+    }
+
+    fn part1(black_tiles: &Self::Input) -> Result<Answer> {
+        Ok(part1(black_tiles).into())
+    }
+
+    fn part2(black_tiles: &Self::Input) -> Result<Answer> {
+        Ok(part2(black_tiles).into())
+    }
 }
 
 fn compute_pattern(input: &str) -> Result<HashSet<HexCoordinates>> {
@@ -72,13 +80,12 @@ fn compute_pattern(input: &str) -> Result<HashSet<HexCoordinates>> {
     Ok(black_tiles)
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let black_tiles = compute_pattern(input)?;
-    Ok(black_tiles.len())
+fn part1(black_tiles: &HashSet<HexCoordinates>) -> usize {
+    black_tiles.len()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut black_tiles = compute_pattern(input)?;
+fn part2(black_tiles: &HashSet<HexCoordinates>) -> usize {
+    let mut black_tiles = black_tiles.clone();
 
     for _ in 0..100 {
         let mut new_black_tiles = black_tiles.clone();
@@ -123,7 +130,7 @@ fn part2(input: &str) -> Result<usize> {
         black_tiles = new_black_tiles;
     }
 
-    Ok(black_tiles.len())
+    black_tiles.len()
 }
 
 /// Hexagonal tile coordinate representation
@@ -132,7 +139,7 @@ fn part2(input: &str) -> Result<usize> {
 ///
 /// https://www.redblobgames.com/grids/hexagons/#coordinates-axial
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
-struct HexCoordinates {
+pub struct HexCoordinates {
     q: i64,
     r: i64,
 }
@@ -194,23 +201,12 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 10);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 528);
+        assert_eq!(part1(&compute_pattern(PROVIDED).unwrap()), 10);
     }
 
     #[test]
     #[ignore]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 2208);
-    }
-
-    #[test]
-    #[ignore]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 4200);
+        assert_eq!(part2(&compute_pattern(PROVIDED).unwrap()), 2208);
     }
 }
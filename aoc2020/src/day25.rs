@@ -1,41 +1,33 @@
-use std::fmt::Write;
-
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day25.txt");
-
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+use aoc::math::{discrete_log, modpow};
+use aoc::{Answer, Solution};
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-
-    Ok(res)
-}
+pub struct Day25;
 
-const PUB_KEY_SUBJECT: u64 = 7;
+impl Solution for Day25 {
+    type Input = (u64, u64);
 
-// - Set the value to itself multiplied by the subject number.
-// - Set the value to the remainder after dividing the value by 20201227.
-fn transform(mut value: u64, subject: u64) -> u64 {
-    value *= subject;
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_keys(input)
+    }
 
-    value % 20201227
+    fn part1(&(card_pub_key, door_pub_key): &Self::Input) -> Result<Answer> {
+        Ok(part1(card_pub_key, door_pub_key)?.into())
+    }
 }
 
-fn find_loop_size(pub_key: u64) -> usize {
-    let mut value = 1;
-    for i in 1.. {
-        value = transform(value, PUB_KEY_SUBJECT);
-
-        if value == pub_key {
-            return i;
-        }
-    }
+const PUB_KEY_SUBJECT: u64 = 7;
+const MODULUS: u64 = 20201227;
 
-    unreachable!()
+// transforming the subject number `loop_size` times computes subject^loop_size mod 20201227,
+// finding the loop size is a discrete logarithm
+fn find_loop_size(pub_key: u64) -> Result<u64> {
+    discrete_log(PUB_KEY_SUBJECT, pub_key, MODULUS)
+        .with_context(|| format!("no loop size produces public key `{}'", pub_key))
 }
 
-fn part1(input: &str) -> Result<u64> {
+fn parse_keys(input: &str) -> Result<(u64, u64)> {
     let mut lines = input.lines();
 
     let card_pub_key: u64 = lines
@@ -47,21 +39,13 @@ fn part1(input: &str) -> Result<u64> {
         .context("no public key found for door")?
         .parse()?;
 
-    let card_loop_size = find_loop_size(card_pub_key);
-    let door_loop_size = find_loop_size(door_pub_key);
-
-    let (loop_size, pub_key) = if card_loop_size < door_loop_size {
-        (card_loop_size, door_pub_key)
-    } else {
-        (door_loop_size, card_pub_key)
-    };
+    Ok((card_pub_key, door_pub_key))
+}
 
-    let mut encryption_key = 1;
-    for _ in 0..loop_size {
-        encryption_key = transform(encryption_key, pub_key);
-    }
+fn part1(card_pub_key: u64, door_pub_key: u64) -> Result<u64> {
+    let card_loop_size = find_loop_size(card_pub_key)?;
 
-    Ok(encryption_key)
+    Ok(modpow(door_pub_key, card_loop_size, MODULUS))
 }
 
 #[cfg(test)]
@@ -72,13 +56,9 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(find_loop_size(5764801), 8);
-        assert_eq!(find_loop_size(17807724), 11);
-        assert_eq!(part1(PROVIDED).unwrap(), 14897079);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 3015200);
+        assert_eq!(find_loop_size(5764801).unwrap(), 8);
+        assert_eq!(find_loop_size(17807724).unwrap(), 11);
+        let (card_pub_key, door_pub_key) = parse_keys(PROVIDED).unwrap();
+        assert_eq!(part1(card_pub_key, door_pub_key).unwrap(), 14897079);
     }
 }
@@ -25,3 +25,5 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
+
+aoc::year!();
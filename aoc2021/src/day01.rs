@@ -1,25 +1,30 @@
-use std::fmt::Write;
-
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day01.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day01;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day01 {
+    type Input = Vec<u64>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.parse::<u64>().map_err(anyhow::Error::new))
+            .collect()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let measurements = input
-        .lines()
-        .map(|line| line.parse::<u64>().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<_>>>()?;
+    fn part1(measurements: &Self::Input) -> Result<Answer> {
+        Ok(part1(measurements).into())
+    }
+
+    fn part2(measurements: &Self::Input) -> Result<Answer> {
+        Ok(part2(measurements).into())
+    }
+}
 
-    Ok(count_increases(measurements.iter()))
+fn part1(measurements: &[u64]) -> usize {
+    count_increases(measurements.iter())
 }
 
 fn count_increases<I, C>(numbers: I) -> usize
@@ -34,15 +39,8 @@ where
         .count()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let measurements = input
-        .lines()
-        .map(|line| line.parse::<u64>().map_err(anyhow::Error::new))
-        .collect::<Result<Vec<_>>>()?;
-
-    Ok(count_increases(
-        measurements.windows(3).map(|w| w.iter().sum::<u64>()),
-    ))
+fn part2(measurements: &[u64]) -> usize {
+    count_increases(measurements.windows(3).map(|w| w.iter().sum::<u64>()))
 }
 
 #[cfg(test)]
@@ -53,21 +51,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 7);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1502);
+        assert_eq!(part1(&Day01::parse(PROVIDED).unwrap()), 7);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 5);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 1538);
+        assert_eq!(part2(&Day01::parse(PROVIDED).unwrap()), 5);
     }
 }
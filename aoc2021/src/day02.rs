@@ -1,23 +1,26 @@
-use std::fmt::Write;
-
 use anyhow::{bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day02.txt");
+use aoc::{Answer, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn part1(commands: &Self::Input) -> Result<Answer> {
+        Ok(part1(commands).into())
+    }
 
-    Ok(res)
+    fn part2(commands: &Self::Input) -> Result<Answer> {
+        Ok(part2(commands).into())
+    }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let commands = input
-        .lines()
-        .map(str::parse::<Command>)
-        .collect::<Result<Vec<_>>>()?;
+fn part1(commands: &[Command]) -> u64 {
     let mut horizontal_pos = 0;
     let mut depth = 0;
 
@@ -29,14 +32,10 @@ fn part1(input: &str) -> Result<u64> {
         }
     }
 
-    Ok(depth * horizontal_pos)
+    depth * horizontal_pos
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let commands = input
-        .lines()
-        .map(str::parse::<Command>)
-        .collect::<Result<Vec<_>>>()?;
+fn part2(commands: &[Command]) -> u64 {
     let mut horizontal_pos = 0;
     let mut depth = 0;
     let mut aim = 0;
@@ -52,10 +51,10 @@ fn part2(input: &str) -> Result<u64> {
         }
     }
 
-    Ok(depth * horizontal_pos)
+    depth * horizontal_pos
 }
 
-enum Command {
+pub enum Command {
     Forward(u64),
     Up(u64),
     Down(u64),
@@ -86,21 +85,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 150);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1962940);
+        assert_eq!(part1(&Day02::parse(PROVIDED).unwrap()), 150);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 900);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 1813664422);
+        assert_eq!(part2(&Day02::parse(PROVIDED).unwrap()), 900);
     }
 }
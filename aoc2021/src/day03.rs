@@ -1,32 +1,38 @@
-use std::fmt::Write;
-
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day03.txt");
+use aoc::{Answer, Solution};
+
+pub struct Day03;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+impl Solution for Day03 {
+    type Input = Vec<String>;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
 
-    Ok(res)
+    fn part1(binary_numbers: &Self::Input) -> Result<Answer> {
+        Ok(part1(binary_numbers).into())
+    }
+
+    fn part2(binary_numbers: &Self::Input) -> Result<Answer> {
+        Ok(part2(binary_numbers)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let binary_numbers: Vec<&str> = input.lines().collect();
+fn part1(binary_numbers: &[String]) -> u64 {
     // all binary numbers should have the same length
     let size = binary_numbers[0].len();
 
-    let gamma = compute_gamma(&binary_numbers, size);
+    let gamma = compute_gamma(binary_numbers, size);
     let epsilon = compute_epsilon(gamma, size);
 
-    Ok(gamma * epsilon)
+    gamma * epsilon
 }
 
 /// Each bit in the gamma rate can be determined by finding the most common bit in the corresponding
 /// position of all numbers in the diagnostic report.
-fn compute_gamma(binary_numbers: &[&str], size: usize) -> u64 {
+fn compute_gamma(binary_numbers: &[String], size: usize) -> u64 {
     let mut gamma = 0;
 
     for pos in 0..size {
@@ -37,10 +43,10 @@ fn compute_gamma(binary_numbers: &[&str], size: usize) -> u64 {
     gamma
 }
 
-fn count_ones(binary_numbers: &[&str], pos: usize) -> usize {
+fn count_ones(binary_numbers: &[String], pos: usize) -> usize {
     binary_numbers
         .iter()
-        .filter(|&&num| num.chars().nth(pos).unwrap() == '1')
+        .filter(|num| num.chars().nth(pos).unwrap() == '1')
         .count()
 }
 
@@ -56,9 +62,7 @@ fn compute_epsilon(gamma: u64, size: usize) -> u64 {
     (!gamma) & mask
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let binary_numbers: Vec<&str> = input.lines().collect();
-
+fn part2(binary_numbers: &[String]) -> Result<u64> {
     // all binary numbers should have the same length
     let size = binary_numbers[0].len();
     #[cfg(debug_assertions)]
@@ -66,13 +70,13 @@ fn part2(input: &str) -> Result<u64> {
         debug_assert_eq!(num.len(), size);
     });
 
-    let oxygen_generator_rating = compute_oxygen_generator_rating(&binary_numbers, size)?;
-    let co2_scrubber_rating = compute_co2_scrubber_rating(&binary_numbers, size)?;
+    let oxygen_generator_rating = compute_oxygen_generator_rating(binary_numbers, size)?;
+    let co2_scrubber_rating = compute_co2_scrubber_rating(binary_numbers, size)?;
 
     Ok(oxygen_generator_rating * co2_scrubber_rating)
 }
 
-fn filter_by_strat<Strat>(binary_numbers: &[&str], size: usize, strat: Strat) -> Result<u64>
+fn filter_by_strat<Strat>(binary_numbers: &[String], size: usize, strat: Strat) -> Result<u64>
 where
     Strat: Fn(bool) -> char,
 {
@@ -92,13 +96,13 @@ where
 
     debug_assert_eq!(numbers.len(), 1);
 
-    u64::from_str_radix(numbers[0], 2).context("couldn't parse binary number")
+    u64::from_str_radix(&numbers[0], 2).context("couldn't parse binary number")
 }
 
 /// To find oxygen generator rating, determine the most common value (0 or 1) in the current bit
 /// position, and keep only numbers with that bit in that position. If 0 and 1 are equally common,
 /// keep values with a 1 in the position being considered.
-fn compute_oxygen_generator_rating(binary_numbers: &[&str], size: usize) -> Result<u64> {
+fn compute_oxygen_generator_rating(binary_numbers: &[String], size: usize) -> Result<u64> {
     filter_by_strat(binary_numbers, size, |one_is_more_common| {
         if one_is_more_common {
             '1'
@@ -111,7 +115,7 @@ fn compute_oxygen_generator_rating(binary_numbers: &[&str], size: usize) -> Resu
 /// To find CO2 scrubber rating, determine the least common value (0 or 1) in the current bit
 /// position, and keep only numbers with that bit in that position. If 0 and 1 are equally
 /// common, keep values with a 0 in the position being considered.
-fn compute_co2_scrubber_rating(binary_numbers: &[&str], size: usize) -> Result<u64> {
+fn compute_co2_scrubber_rating(binary_numbers: &[String], size: usize) -> Result<u64> {
     filter_by_strat(binary_numbers, size, |one_is_more_common| {
         if one_is_more_common {
             '0'
@@ -129,7 +133,7 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        let binary_numbers: Vec<&str> = PROVIDED.lines().collect();
+        let binary_numbers = Day03::parse(PROVIDED).unwrap();
         let size = binary_numbers[0].len();
 
         let gamma = compute_gamma(&binary_numbers, size);
@@ -139,14 +143,9 @@ mod tests {
         assert_eq!(epsilon, 9);
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 3429254);
-    }
-
     #[test]
     fn part2_provided() {
-        let binary_numbers: Vec<&str> = PROVIDED.lines().collect();
+        let binary_numbers = Day03::parse(PROVIDED).unwrap();
         let size = binary_numbers[0].len();
 
         let oxygen_generator_rating =
@@ -156,9 +155,4 @@ mod tests {
         let co2_scrubber_rating = compute_co2_scrubber_rating(&binary_numbers, size).unwrap();
         assert_eq!(co2_scrubber_rating, 10);
     }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 5410338);
-    }
 }
@@ -1,40 +1,37 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 
 use anyhow::{anyhow, Context, Result};
 
-const INPUT: &str = include_str!("../input/day04.txt");
+use aoc::{Answer, Solution};
 
 const GRID_WIDTH: usize = 5;
 const GRID_HEIGHT: usize = 5;
 const GRID_SIZE: usize = GRID_WIDTH * GRID_HEIGHT;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day04;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day04 {
+    type Input = Bingo;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(bingo: &Self::Input) -> Result<Answer> {
+        Ok(part1(bingo)?.into())
+    }
 
-fn part1(input: &str) -> Result<u64> {
-    let (draws, grids) = input
-        .split_once("\n\n")
-        .context("couldn't split draws from grids")?;
+    fn part2(bingo: &Self::Input) -> Result<Answer> {
+        Ok(part2(bingo)?.into())
+    }
+}
 
-    let draws = draws
-        .split(',')
-        .map(|num| num.parse::<u8>().context("couldn't parse drawn number:"))
-        .collect::<Result<Vec<_>>>()?;
-    let mut grids = grids
-        .split("\n\n")
-        .map(str::parse::<Grid>)
-        .collect::<Result<Vec<_>>>()?;
+fn part1(bingo: &Bingo) -> Result<u64> {
+    let mut grids = bingo.grids.clone();
 
     let (mut wdraw, mut wgrid) = (None, None);
 
-    'draw_loop: for draw in draws {
+    'draw_loop: for &draw in &bingo.draws {
         for grid in &mut grids {
             grid.mark(draw);
             if grid.is_winning() {
@@ -53,21 +50,10 @@ fn part1(input: &str) -> Result<u64> {
     }
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let (draws, grids) = input
-        .split_once("\n\n")
-        .context("couldn't split draws from grids")?;
+fn part2(bingo: &Bingo) -> Result<u64> {
+    let mut grids = bingo.grids.clone();
 
-    let draws = draws
-        .split(',')
-        .map(|num| num.parse::<u8>().context("couldn't parse drawn number:"))
-        .collect::<Result<Vec<_>>>()?;
-    let mut grids = grids
-        .split("\n\n")
-        .map(str::parse::<Grid>)
-        .collect::<Result<Vec<_>>>()?;
-
-    let mut draws = draws.into_iter();
+    let mut draws = bingo.draws.iter().copied();
 
     while grids.len() > 1 {
         let draw = draws
@@ -91,6 +77,32 @@ fn part2(input: &str) -> Result<u64> {
     Err(anyhow!("last grid never wins, this is not expected"))
 }
 
+pub struct Bingo {
+    draws: Vec<u8>,
+    grids: Vec<Grid>,
+}
+
+impl std::str::FromStr for Bingo {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (draws, grids) = s
+            .split_once("\n\n")
+            .context("couldn't split draws from grids")?;
+
+        let draws = draws
+            .split(',')
+            .map(|num| num.parse::<u8>().context("couldn't parse drawn number:"))
+            .collect::<Result<_>>()?;
+        let grids = grids
+            .split("\n\n")
+            .map(str::parse::<Grid>)
+            .collect::<Result<_>>()?;
+
+        Ok(Bingo { draws, grids })
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Grid {
     number_to_pos: HashMap<u8, (usize, usize)>,
@@ -168,21 +180,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 4512);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 45031);
+        assert_eq!(part1(&Day04::parse(PROVIDED).unwrap()).unwrap(), 4512);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 1924);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2568);
+        assert_eq!(part2(&Day04::parse(PROVIDED).unwrap()).unwrap(), 1924);
     }
 }
@@ -1,26 +1,29 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day05.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day05;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day05 {
+    type Input = Vec<Line>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let lines = input
-        .lines()
-        .map(str::parse::<Line>)
-        .collect::<Result<Vec<_>>>()?;
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        Ok(part1(lines).into())
+    }
 
+    fn part2(lines: &Self::Input) -> Result<Answer> {
+        Ok(part2(lines).into())
+    }
+}
+
+fn part1(lines: &[Line]) -> usize {
     let mut grid = HashMap::new();
 
     lines
@@ -32,15 +35,10 @@ fn part1(input: &str) -> Result<usize> {
             }
         });
 
-    Ok(grid.into_values().filter(|c| *c > 1).count())
+    grid.into_values().filter(|c| *c > 1).count()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let lines = input
-        .lines()
-        .map(str::parse::<Line>)
-        .collect::<Result<Vec<_>>>()?;
-
+fn part2(lines: &[Line]) -> usize {
     let mut grid = HashMap::new();
 
     lines.iter().for_each(|l| {
@@ -49,7 +47,7 @@ fn part2(input: &str) -> Result<usize> {
         }
     });
 
-    Ok(grid.into_values().filter(|c| *c > 1).count())
+    grid.into_values().filter(|c| *c > 1).count()
 }
 
 struct PointIterator {
@@ -104,7 +102,7 @@ impl Iterator for PointIterator {
 }
 
 #[derive(Debug)]
-struct Line {
+pub struct Line {
     from: (usize, usize),
     to: (usize, usize),
 }
@@ -157,21 +155,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 5);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 4745);
+        assert_eq!(part1(&Day05::parse(PROVIDED).unwrap()), 5);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 12);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 18442);
+        assert_eq!(part2(&Day05::parse(PROVIDED).unwrap()), 12);
     }
 }
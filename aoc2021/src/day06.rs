@@ -1,37 +1,42 @@
 use std::collections::VecDeque;
-use std::fmt::Write;
 use std::str;
 
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day06.txt");
+use aoc::{Answer, Solution};
 
 const SPAWNING_DELAY: usize = 7;
 const TURNS_PART_1: usize = 80;
 const TURNS_PART_2: usize = 256;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day06;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day06 {
+    type Input = School;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.trim().parse()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let mut school = input.trim().parse::<School>()?;
+    fn part1(school: &Self::Input) -> Result<Answer> {
+        Ok(part1(school).into())
+    }
 
-    Ok(school.grow_for(TURNS_PART_1))
+    fn part2(school: &Self::Input) -> Result<Answer> {
+        Ok(part2(school).into())
+    }
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut school = input.trim().parse::<School>()?;
+fn part1(school: &School) -> usize {
+    school.clone().grow_for(TURNS_PART_1)
+}
 
-    Ok(school.grow_for(TURNS_PART_2))
+fn part2(school: &School) -> usize {
+    school.clone().grow_for(TURNS_PART_2)
 }
 
-struct School {
+#[derive(Clone)]
+pub struct School {
     fish_timers: VecDeque<usize>,
 }
 
@@ -81,21 +86,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 5934);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 350149);
+        assert_eq!(part1(&Day06::parse(PROVIDED).unwrap()), 5934);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 26984457539);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 1590327954513);
+        assert_eq!(part2(&Day06::parse(PROVIDED).unwrap()), 26984457539);
     }
 }
@@ -1,34 +1,41 @@
-use std::fmt::Write;
-
 use anyhow::{Context, Result};
+
+use aoc::{Answer, Solution};
 use rand::Rng;
 
-const INPUT: &str = include_str!("../input/day07.txt");
+pub struct Day07;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+impl Solution for Day07 {
+    type Input = Vec<u64>;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .trim()
+            .split(',')
+            .map(|n| n.parse::<u64>().context("couldn't parse position"))
+            .collect()
+    }
 
-    Ok(res)
+    fn part1(horizontal_positions: &Self::Input) -> Result<Answer> {
+        Ok(part1(horizontal_positions).into())
+    }
+
+    fn part2(horizontal_positions: &Self::Input) -> Result<Answer> {
+        Ok(part2(horizontal_positions).into())
+    }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let mut horizontal_positions = input
-        .trim()
-        .split(',')
-        .map(|n| n.parse::<u64>().context("couldn't parse position"))
-        .collect::<Result<Vec<_>>>()?;
+fn part1(horizontal_positions: &[u64]) -> u64 {
+    let mut horizontal_positions = horizontal_positions.to_vec();
 
     let median_rank = horizontal_positions.len() / 2;
     let median = selection(&mut horizontal_positions, median_rank);
 
-    Ok(horizontal_positions
+    horizontal_positions
         .iter()
         // TODO: use abs_diff when stabilized
         .map(|n| abs_diff(*n, median))
-        .sum())
+        .sum()
 }
 
 fn selection<T>(data: &mut [T], i: usize) -> T
@@ -77,23 +84,17 @@ where
     }
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let horizontal_positions = input
-        .trim()
-        .split(',')
-        .map(|n| n.parse::<u64>().context("couldn't parse position"))
-        .collect::<Result<Vec<_>>>()?;
-
+fn part2(horizontal_positions: &[u64]) -> u64 {
     let min = *horizontal_positions.iter().min().unwrap();
     let max = *horizontal_positions.iter().max().unwrap();
 
-    let optimal_distance = minimize_binary_search(&horizontal_positions, min, max, part2_distance);
+    let optimal_distance = minimize_binary_search(horizontal_positions, min, max, part2_distance);
     let minimum_fuel = horizontal_positions
         .iter()
         .map(|n| part2_distance(*n, optimal_distance))
         .sum::<u64>();
 
-    Ok(minimum_fuel)
+    minimum_fuel
 }
 
 fn minimize_binary_search<F>(positions: &[u64], min: u64, max: u64, distance: F) -> u64
@@ -138,22 +139,12 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 37);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 340056);
+        assert_eq!(part1(&Day07::parse(PROVIDED).unwrap()), 37);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 168);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 96592275);
+        assert_eq!(part2(&Day07::parse(PROVIDED).unwrap()), 168);
     }
 
     #[test]
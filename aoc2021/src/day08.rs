@@ -1,33 +1,30 @@
-use std::fmt::Write;
-
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day08.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day08;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day08 {
+    type Input = Vec<Entry>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let entries = input
-        .lines()
-        .map(TryInto::try_into)
-        .collect::<Result<Vec<Entry>>>()?;
+    fn part1(entries: &Self::Input) -> Result<Answer> {
+        Ok(part1(entries).into())
+    }
 
-    Ok(entries.iter().map(Entry::count_easy_digits_in_output).sum())
+    fn part2(entries: &Self::Input) -> Result<Answer> {
+        Ok(part2(entries)?.into())
+    }
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let entries = input
-        .lines()
-        .map(TryInto::try_into)
-        .collect::<Result<Vec<Entry>>>()?;
+fn part1(entries: &[Entry]) -> usize {
+    entries.iter().map(Entry::count_easy_digits_in_output).sum()
+}
 
+fn part2(entries: &[Entry]) -> Result<u64> {
     let mut sum = 0;
 
     for entry in entries {
@@ -38,12 +35,12 @@ fn part2(input: &str) -> Result<u64> {
     Ok(sum)
 }
 
-struct Entry<'a> {
-    four_digits_output: Vec<&'a str>,
-    unique_signals: Vec<&'a str>,
+pub struct Entry {
+    four_digits_output: Vec<String>,
+    unique_signals: Vec<String>,
 }
 
-impl<'a> Entry<'a> {
+impl Entry {
     fn count_easy_digits_in_output(&self) -> usize {
         self.four_digits_output
             .iter()
@@ -61,14 +58,14 @@ impl<'a> Entry<'a> {
         }
     }
 
-    fn compute_mapping(&self) -> Result<Vec<(&'a str, u64)>> {
+    fn compute_mapping(&self) -> Result<Vec<(&str, u64)>> {
         let mut mapping = Vec::new();
 
         // first, let's get the easy digits
         let easy_digits: Vec<(&str, u64)> = self
             .unique_signals
             .iter()
-            .filter_map(|signal| Some(*signal).zip(Self::translate_easy_digit(signal)))
+            .filter_map(|signal| Some(signal.as_str()).zip(Self::translate_easy_digit(signal)))
             .collect();
 
         // now we can get `9` for free: it's the signal that uses 6 segments and has 4 in common
@@ -78,11 +75,12 @@ impl<'a> Entry<'a> {
             .iter()
             .find(|(_, translation)| *translation == 4)
             .context("no signal found for the digit 4!")?;
-        let nine = *self
+        let nine = self
             .unique_signals
             .iter()
             .filter(|signal| signal.len() == 6)
             .find(|signal| four.chars().all(|c| signal.contains(c)))
+            .map(String::as_str)
             .context("couldn't identify any signal corresponding to the digit 9!")?;
         mapping.push((nine, 9));
 
@@ -91,41 +89,45 @@ impl<'a> Entry<'a> {
             .iter()
             .find(|(_, translation)| *translation == 1)
             .context("no signal found for the digit 1!")?;
-        let zero = *self
+        let zero = self
             .unique_signals
             .iter()
             .filter(|&signal| signal.len() == 6)
             .filter(|&signal| *signal != nine)
             .find(|signal| one.chars().all(|c| signal.contains(c)))
+            .map(String::as_str)
             .context("couldn't identify any signal corresponding to the digit 0!")?;
         mapping.push((zero, 0));
 
         // `6` is an easy one now, the only other signal with 6 segments, that isn't nine or zero.
-        let six = *self
+        let six = self
             .unique_signals
             .iter()
             .filter(|signal| signal.len() == 6)
             .find(|&signal| *signal != nine && *signal != zero)
+            .map(String::as_str)
             .context("couldn't identify any signal corresponding to the digit 6!")?;
         mapping.push((six, 6));
 
         // `2`, `3` and `5` have 5 segments each.
         //
         // `3` has 2 segments in common with `1`.
-        let three = *self
+        let three = self
             .unique_signals
             .iter()
             .filter(|signal| signal.len() == 5)
             .find(|signal| one.chars().all(|c| signal.contains(c)))
+            .map(String::as_str)
             .context("couldn't identify any signal corresponding to the digit 3!")?;
         mapping.push((three, 3));
 
         // `5` has all its segments used in `6`, `2` doesn't.
-        let five = *self
+        let five = self
             .unique_signals
             .iter()
             .filter(|signal| signal.len() == 5)
             .find(|signal| signal.chars().all(|c| six.contains(c)))
+            .map(String::as_str)
             .context("couldn't identify any signal corresponding to the digit 5!")?;
         mapping.push((five, 5));
 
@@ -135,6 +137,7 @@ impl<'a> Entry<'a> {
             .iter()
             .filter(|signal| signal.len() == 5)
             .find(|&signal| *signal != five && *signal != three)
+            .map(String::as_str)
             .context("couldn't identify any signal corresponding to the digit 2!")?;
         mapping.push((two, 2));
 
@@ -171,15 +174,15 @@ impl<'a> Entry<'a> {
     }
 }
 
-impl<'a> TryFrom<&'a str> for Entry<'a> {
-    type Error = anyhow::Error;
+impl std::str::FromStr for Entry {
+    type Err = anyhow::Error;
 
-    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+    fn from_str(s: &str) -> Result<Self> {
         let (signals, output) = s.split_once(" | ").context("couldn't split on ` | `")?;
 
         Ok(Self {
-            four_digits_output: output.trim().split(' ').collect(),
-            unique_signals: signals.trim().split(' ').collect(),
+            four_digits_output: output.trim().split(' ').map(str::to_string).collect(),
+            unique_signals: signals.trim().split(' ').map(str::to_string).collect(),
         })
     }
 }
@@ -192,21 +195,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 26);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 488);
+        assert_eq!(part1(&Day08::parse(PROVIDED).unwrap()), 26);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 61229);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 1040429);
+        assert_eq!(part2(&Day08::parse(PROVIDED).unwrap()).unwrap(), 61229);
     }
 }
@@ -1,30 +1,37 @@
 use std::collections::HashSet;
-use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day09.txt");
+use aoc::grid::Point;
+use aoc::{Answer, Grid, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day09;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day09 {
+    type Input = HeightMap;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(height_map: &Self::Input) -> Result<Answer> {
+        Ok(part1(height_map).into())
+    }
 
-fn part1(input: &str) -> Result<u64> {
-    let height_map: HeightMap = input.parse()?;
+    fn part2(height_map: &Self::Input) -> Result<Answer> {
+        Ok(part2(height_map)?.into())
+    }
+}
 
-    Ok(height_map
+fn part1(height_map: &HeightMap) -> u64 {
+    height_map
         .low_points()
         .map(|(x, y)| height_map.risk_level(x, y))
-        .sum())
+        .sum()
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let mut height_map: HeightMap = input.parse()?;
+fn part2(height_map: &HeightMap) -> Result<u64> {
+    let mut height_map = height_map.clone();
 
     let low_points: Vec<_> = height_map.low_points().collect();
 
@@ -42,39 +49,10 @@ fn part2(input: &str) -> Result<u64> {
         .context("couldn't find 3 bassins")
 }
 
-#[derive(Clone, Copy)]
-enum Neighbour {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Neighbour {
-    fn apply(&self, x: usize, y: usize, width: usize, height: usize) -> Option<(usize, usize)> {
-        match self {
-            Neighbour::Left if x > 0 => Some((x - 1, y)),
-            Neighbour::Right if x < width - 1 => Some((x + 1, y)),
-            Neighbour::Up if y > 0 => Some((x, y - 1)),
-            Neighbour::Down if y < height - 1 => Some((x, y + 1)),
-            _ => None,
-        }
-    }
-
-    const ALL: &'static [Self] = &[
-        Neighbour::Left,
-        Neighbour::Right,
-        Neighbour::Up,
-        Neighbour::Down,
-    ];
-}
-
-#[derive(Debug)]
-struct HeightMap {
-    heights: Vec<u8>,
-    width: usize,
-    height: usize,
-    filled_points: HashSet<(usize, usize)>,
+#[derive(Debug, Clone)]
+pub struct HeightMap {
+    heights: Grid<u8>,
+    filled_points: HashSet<Point>,
 }
 
 impl HeightMap {
@@ -88,28 +66,19 @@ impl HeightMap {
         }
         self.filled_points.insert((x, y));
 
-        self.neighbours(x, y)
+        self.heights
+            .neighbours4((x, y))
             .map(|(nx, ny)| self.fill_basin(nx, ny))
             .sum::<u64>()
             + 1
     }
 
-    fn low_points(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
-        (0..self.height)
-            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
-            .filter(|&(x, y)| {
-                self.neighbours(x, y)
-                    .all(|(nx, ny)| self.get(x, y) < self.get(nx, ny))
-            })
-    }
-
-    fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + 'static {
-        let width = self.width;
-        let height = self.height;
-        Neighbour::ALL
-            .iter()
-            .copied()
-            .filter_map(move |neighbour| neighbour.apply(x, y, width, height))
+    fn low_points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.heights.points().filter(|&(x, y)| {
+            self.heights
+                .neighbours4((x, y))
+                .all(|(nx, ny)| self.get(x, y) < self.get(nx, ny))
+        })
     }
 
     fn risk_level(&self, x: usize, y: usize) -> u64 {
@@ -117,7 +86,7 @@ impl HeightMap {
     }
 
     fn get(&self, x: usize, y: usize) -> u8 {
-        self.heights[y * self.width + x]
+        self.heights[(x, y)]
     }
 }
 
@@ -125,32 +94,14 @@ impl std::str::FromStr for HeightMap {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let mut heights = Vec::new();
-
-        let mut height = 0;
-        let mut width = None;
-        for line in s.lines().map(str::trim) {
-            let line = line
-                .chars()
-                .map(|chr| {
-                    chr.to_digit(10)
-                        .map(|digit| digit as u8)
-                        .with_context(|| format!("cannot parse char {} to digit", chr))
-                })
-                .collect::<Result<Vec<_>>>()?;
-
-            if width.is_none() {
-                width = Some(line.len());
-            }
-
-            height += 1;
-            heights.extend_from_slice(&line);
-        }
+        let heights = Grid::parse_with(s.trim(), |chr| {
+            chr.to_digit(10)
+                .map(|digit| digit as u8)
+                .with_context(|| format!("cannot parse char {} to digit", chr))
+        })?;
 
         Ok(HeightMap {
             heights,
-            width: width.context("0 lines parsed, width never computed")?,
-            height,
             filled_points: HashSet::new(),
         })
     }
@@ -164,21 +115,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 15);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 522);
+        assert_eq!(part1(&Day09::parse(PROVIDED).unwrap()), 15);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 1134);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 916688);
+        assert_eq!(part2(&Day09::parse(PROVIDED).unwrap()).unwrap(), 1134);
     }
 }
@@ -1,39 +1,36 @@
-use std::fmt::Write;
-
 use anyhow::{bail, Result};
 
-const INPUT: &str = include_str!("../input/day10.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day10;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day10 {
+    type Input = Vec<Line>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
 
-fn part1(input: &str) -> Result<u64> {
-    let lines = input
-        .lines()
-        .map(str::parse::<Line>)
-        .collect::<Result<Vec<_>>>()?;
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        Ok(part1(lines).into())
+    }
 
-    Ok(lines.iter().filter_map(Line::syntax_checking_score).sum())
+    fn part2(lines: &Self::Input) -> Result<Answer> {
+        Ok(part2(lines).into())
+    }
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let lines = input
-        .lines()
-        .map(str::parse::<Line>)
-        .collect::<Result<Vec<_>>>()?;
+fn part1(lines: &[Line]) -> u64 {
+    lines.iter().filter_map(Line::syntax_checking_score).sum()
+}
 
+fn part2(lines: &[Line]) -> u64 {
     let mut scores: Vec<u64> = lines.iter().filter_map(Line::completion_score).collect();
     scores.sort_unstable();
 
     debug_assert!(scores.len() % 2 == 1);
 
-    Ok(scores[scores.len() / 2])
+    scores[scores.len() / 2]
 }
 
 enum SymbolState {
@@ -95,7 +92,7 @@ impl TryFrom<char> for Symbol {
     }
 }
 
-struct Line {
+pub struct Line {
     symbols: Vec<SymbolState>,
 }
 
@@ -161,21 +158,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 26397);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 394647);
+        assert_eq!(part1(&Day10::parse(PROVIDED).unwrap()), 26397);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 288957);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2380061249);
+        assert_eq!(part2(&Day10::parse(PROVIDED).unwrap()), 288957);
     }
 }
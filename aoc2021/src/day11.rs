@@ -1,26 +1,33 @@
-use std::fmt::Write;
-
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day11.txt");
+use aoc::{Answer, Grid, Solution};
+
+pub struct Day11;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+impl Solution for Day11 {
+    type Input = OctopusGrid;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
 
-    Ok(res)
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(part2(grid)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let mut grid: OctopusGrid = input.parse()?;
+fn part1(grid: &OctopusGrid) -> usize {
+    let mut grid = grid.clone();
 
-    Ok((0..100).map(|_| grid.step()).sum())
+    (0..100).map(|_| grid.step()).sum()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut grid: OctopusGrid = input.parse()?;
+fn part2(grid: &OctopusGrid) -> Result<usize> {
+    let mut grid = grid.clone();
 
     let (_, step) = (0..)
         .map(|step| (grid.step(), step))
@@ -72,77 +79,16 @@ impl TryFrom<char> for Octopus {
     }
 }
 
-#[derive(Clone, Copy)]
-enum Neighbour {
-    Up,
-    Down,
-    Left,
-    Right,
-    UpperLeft,
-    UpperRight,
-    LowerLeft,
-    LowerRight,
-}
-
-impl Neighbour {
-    fn apply(&self, x: usize, y: usize, width: usize, height: usize) -> Option<(usize, usize)> {
-        match self {
-            Neighbour::Left if x > 0 => Some((x - 1, y)),
-            Neighbour::Right if x < width - 1 => Some((x + 1, y)),
-            Neighbour::Up if y > 0 => Some((x, y - 1)),
-            Neighbour::Down if y < height - 1 => Some((x, y + 1)),
-            Neighbour::UpperLeft => Neighbour::Left
-                .apply(x, y, width, height)
-                .and_then(|(x, y)| Neighbour::Up.apply(x, y, width, height)),
-            Neighbour::UpperRight => Neighbour::Right
-                .apply(x, y, width, height)
-                .and_then(|(x, y)| Neighbour::Up.apply(x, y, width, height)),
-            Neighbour::LowerLeft => Neighbour::Left
-                .apply(x, y, width, height)
-                .and_then(|(x, y)| Neighbour::Down.apply(x, y, width, height)),
-            Neighbour::LowerRight => Neighbour::Right
-                .apply(x, y, width, height)
-                .and_then(|(x, y)| Neighbour::Down.apply(x, y, width, height)),
-            _ => None,
-        }
-    }
-
-    const ALL: &'static [Self] = &[
-        Neighbour::Left,
-        Neighbour::Right,
-        Neighbour::Up,
-        Neighbour::Down,
-        Neighbour::UpperLeft,
-        Neighbour::UpperRight,
-        Neighbour::LowerLeft,
-        Neighbour::LowerRight,
-    ];
-}
-
-#[derive(Debug)]
-struct OctopusGrid {
-    octopuses: Vec<Octopus>,
-    width: usize,
-    height: usize,
+#[derive(Debug, Clone)]
+pub struct OctopusGrid {
+    octopuses: Grid<Octopus>,
 }
 
 impl OctopusGrid {
-    fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + 'static {
-        let width = self.width;
-        let height = self.height;
-        Neighbour::ALL
-            .iter()
-            .copied()
-            .filter_map(move |neighbour| neighbour.apply(x, y, width, height))
-    }
-
-    fn get_mut(&mut self, x: usize, y: usize) -> &mut Octopus {
-        &mut self.octopuses[y * self.width + x]
-    }
-
     fn step(&mut self) -> usize {
         // First, the energy level of each octopus increases by 1.
         self.octopuses
+            .cells_mut()
             .iter_mut()
             .for_each(Octopus::increment_energy_level);
 
@@ -155,11 +101,10 @@ impl OctopusGrid {
         let mut flashes = 0;
         while flashed {
             flashed = false;
-            for index in 0..self.octopuses.len() {
-                if self.octopuses[index].flashes() {
-                    let (x, y) = (index % self.width, index / self.width);
-                    for (x, y) in self.neighbours(x, y) {
-                        self.get_mut(x, y).increment_energy_level();
+            for point in self.octopuses.points() {
+                if self.octopuses[point].flashes() {
+                    for neighbour in self.octopuses.neighbours8(point) {
+                        self.octopuses[neighbour].increment_energy_level();
                     }
 
                     flashed = true;
@@ -171,6 +116,7 @@ impl OctopusGrid {
         // Finally, any octopus that flashed during this step has its energy level set to 0, as it
         // used all of its energy to flash.
         self.octopuses
+            .cells_mut()
             .iter_mut()
             .for_each(Octopus::reset_energy_level);
 
@@ -182,28 +128,8 @@ impl std::str::FromStr for OctopusGrid {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut octopuses = Vec::new();
-
-        let mut height = 0;
-        let mut width = None;
-        for line in s.lines().map(str::trim) {
-            let octopus_line = line
-                .chars()
-                .map(TryFrom::try_from)
-                .collect::<Result<Vec<_>>>()?;
-
-            if width.is_none() {
-                width = Some(octopus_line.len());
-            }
-
-            height += 1;
-            octopuses.extend_from_slice(&octopus_line);
-        }
-
         Ok(OctopusGrid {
-            octopuses,
-            width: width.context("0 lines parsed, width never computed")?,
-            height,
+            octopuses: s.trim().parse()?,
         })
     }
 }
@@ -216,21 +142,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 1656);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1588);
+        assert_eq!(part1(&Day11::parse(PROVIDED).unwrap()), 1656);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 195);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 517);
+        assert_eq!(part2(&Day11::parse(PROVIDED).unwrap()).unwrap(), 195);
     }
 }
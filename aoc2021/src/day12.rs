@@ -1,38 +1,42 @@
 use std::collections::{HashMap, HashSet};
-use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day12.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day12;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day12 {
+    type Input = CaveMap;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
 
-fn part1(input: &str) -> Result<usize> {
-    let cave_map: CaveMap = input.try_into()?;
+    fn part1(cave_map: &Self::Input) -> Result<Answer> {
+        Ok(part1(cave_map)?.into())
+    }
 
-    cave_map.count_paths()
+    fn part2(cave_map: &Self::Input) -> Result<Answer> {
+        Ok(part2(cave_map)?.into())
+    }
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let cave_map: CaveMap = input.try_into()?;
+fn part1(cave_map: &CaveMap) -> Result<usize> {
+    cave_map.count_paths()
+}
 
+fn part2(cave_map: &CaveMap) -> Result<usize> {
     cave_map.count_paths_twice()
 }
 
-#[derive(Clone, Copy)]
-struct Cave<'a> {
-    name: &'a str,
+#[derive(Clone)]
+struct Cave {
+    name: String,
     small: bool,
 }
 
-impl<'a> Cave<'a> {
+impl Cave {
     fn is_end(&self) -> bool {
         self.name == "end"
     }
@@ -46,35 +50,35 @@ impl<'a> Cave<'a> {
     }
 }
 
-impl<'a> From<&'a str> for Cave<'a> {
-    fn from(s: &'a str) -> Self {
+impl From<&str> for Cave {
+    fn from(s: &str) -> Self {
         Cave {
-            name: s,
+            name: s.to_string(),
             small: s.chars().all(char::is_lowercase),
         }
     }
 }
 
-impl<'a> std::hash::Hash for Cave<'a> {
+impl std::hash::Hash for Cave {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.name.hash(state);
     }
 }
 
-impl<'a> std::cmp::PartialEq for Cave<'a> {
+impl std::cmp::PartialEq for Cave {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
     }
 }
-impl<'a> std::cmp::Eq for Cave<'a> {}
+impl std::cmp::Eq for Cave {}
 
-struct CaveMap<'a> {
-    connections: HashMap<Cave<'a>, Vec<Cave<'a>>>,
+pub struct CaveMap {
+    connections: HashMap<Cave, Vec<Cave>>,
 }
 
-impl<'a> CaveMap<'a> {
+impl CaveMap {
     fn count_paths(&self) -> Result<usize> {
-        let start = *self
+        let start = self
             .connections
             .keys()
             .find(|cave| cave.is_start())
@@ -82,7 +86,7 @@ impl<'a> CaveMap<'a> {
         Ok(self.count_paths_rec(start, HashSet::new()))
     }
 
-    fn count_paths_rec(&self, from: Cave<'a>, mut small_seen: HashSet<Cave<'a>>) -> usize {
+    fn count_paths_rec<'a>(&'a self, from: &'a Cave, mut small_seen: HashSet<&'a Cave>) -> usize {
         if from.is_end() {
             return 1;
         }
@@ -95,15 +99,15 @@ impl<'a> CaveMap<'a> {
         }
 
         let mut paths = 0;
-        for dst in &self.connections[&from] {
-            paths += self.count_paths_rec(*dst, small_seen.clone());
+        for dst in &self.connections[from] {
+            paths += self.count_paths_rec(dst, small_seen.clone());
         }
 
         paths
     }
 
     fn count_paths_twice(&self) -> Result<usize> {
-        let start = *self
+        let start = self
             .connections
             .keys()
             .find(|cave| cave.is_start())
@@ -111,10 +115,10 @@ impl<'a> CaveMap<'a> {
         Ok(self.count_paths_twice_rec(start, HashSet::new(), false))
     }
 
-    fn count_paths_twice_rec(
-        &self,
-        from: Cave<'a>,
-        mut small_seen: HashSet<Cave<'a>>,
+    fn count_paths_twice_rec<'a>(
+        &'a self,
+        from: &'a Cave,
+        mut small_seen: HashSet<&'a Cave>,
         visited_twice: bool,
     ) -> usize {
         if from.is_end() {
@@ -126,14 +130,14 @@ impl<'a> CaveMap<'a> {
         }
 
         let mut paths = 0;
-        for dst in &self.connections[&from] {
+        for dst in &self.connections[from] {
             let will_visit_twice = small_seen.contains(dst) && dst.is_small();
             if will_visit_twice && (visited_twice || dst.is_start()) {
                 continue;
             }
 
             paths += self.count_paths_twice_rec(
-                *dst,
+                dst,
                 small_seen.clone(),
                 visited_twice || will_visit_twice,
             );
@@ -143,10 +147,10 @@ impl<'a> CaveMap<'a> {
     }
 }
 
-impl<'a> TryFrom<&'a str> for CaveMap<'a> {
-    type Error = anyhow::Error;
+impl std::str::FromStr for CaveMap {
+    type Err = anyhow::Error;
 
-    fn try_from(s: &'a str) -> Result<Self> {
+    fn from_str(s: &str) -> Result<Self> {
         let mut map = HashMap::new();
 
         for line in s.lines().map(str::trim) {
@@ -175,25 +179,15 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED1).unwrap(), 10);
-        assert_eq!(part1(PROVIDED2).unwrap(), 19);
-        assert_eq!(part1(PROVIDED3).unwrap(), 226);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 5252);
+        assert_eq!(part1(&PROVIDED1.parse().unwrap()).unwrap(), 10);
+        assert_eq!(part1(&PROVIDED2.parse().unwrap()).unwrap(), 19);
+        assert_eq!(part1(&PROVIDED3.parse().unwrap()).unwrap(), 226);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED1).unwrap(), 36);
-        assert_eq!(part2(PROVIDED2).unwrap(), 103);
-        assert_eq!(part2(PROVIDED3).unwrap(), 3509);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 147784);
+        assert_eq!(part2(&PROVIDED1.parse().unwrap()).unwrap(), 36);
+        assert_eq!(part2(&PROVIDED2.parse().unwrap()).unwrap(), 103);
+        assert_eq!(part2(&PROVIDED3.parse().unwrap()).unwrap(), 3509);
     }
 }
@@ -3,27 +3,32 @@ use std::fmt::Write;
 
 use anyhow::{anyhow, Context, Result};
 
-const INPUT: &str = include_str!("../input/day13.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day13;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day13 {
+    type Input = Manual;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(manual: &Self::Input) -> Result<Answer> {
+        Ok(part1(manual)?.into())
+    }
+
+    fn part2(manual: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Grid(part2(manual)))
+    }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let (sheet, fold_instructions) = input.split_once("\n\n").context("couldn't split input")?;
-    let mut sheet: PaperSheet = sheet.parse()?;
-    let fold_instructions: Vec<FoldInstruction> = fold_instructions
-        .lines()
-        .map(|l| l.trim().parse())
-        .collect::<Result<_>>()?;
+fn part1(manual: &Manual) -> Result<usize> {
+    let mut sheet = manual.sheet.clone();
 
     sheet.apply(
-        *fold_instructions
+        *manual
+            .fold_instructions
             .first()
             .context("had 0 fold instructions")?,
     );
@@ -31,21 +36,38 @@ fn part1(input: &str) -> Result<usize> {
     Ok(sheet.num_points())
 }
 
-fn part2(input: &str) -> Result<String> {
-    let (sheet, fold_instructions) = input.split_once("\n\n").context("couldn't split input")?;
-    let mut sheet: PaperSheet = sheet.parse()?;
-    let fold_instructions: Vec<FoldInstruction> = fold_instructions
-        .lines()
-        .map(|l| l.trim().parse())
-        .collect::<Result<_>>()?;
+fn part2(manual: &Manual) -> String {
+    let mut sheet = manual.sheet.clone();
 
-    for instr in fold_instructions {
+    for &instr in &manual.fold_instructions {
         sheet.apply(instr);
     }
 
-    Ok(format!("{}", sheet))
+    format!("{}", sheet)
+}
+
+pub struct Manual {
+    sheet: PaperSheet,
+    fold_instructions: Vec<FoldInstruction>,
 }
 
+impl std::str::FromStr for Manual {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (sheet, fold_instructions) = s.split_once("\n\n").context("couldn't split input")?;
+
+        Ok(Manual {
+            sheet: sheet.parse()?,
+            fold_instructions: fold_instructions
+                .lines()
+                .map(|l| l.trim().parse())
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+#[derive(Clone)]
 struct PaperSheet {
     points: HashSet<(usize, usize)>,
 }
@@ -94,9 +116,6 @@ impl PaperSheet {
 
 impl std::fmt::Display for PaperSheet {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // make sure first row appears on its own line
-        writeln!(f)?;
-
         if self.points.is_empty() {
             return writeln!(f, "`empty paper sheet`");
         }
@@ -171,39 +190,19 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 17);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 753);
+        assert_eq!(part1(&Day13::parse(PROVIDED).unwrap()).unwrap(), 17);
     }
 
     #[test]
     fn part2_provided() {
         assert_eq!(
-            part2(PROVIDED).unwrap(),
-            "
+            part2(&Day13::parse(PROVIDED).unwrap()),
+            "\
 #####
 #...#
 #...#
 #...#
 #####
-"
-        );
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(
-            part2(INPUT).unwrap(),
-            "
-#..#.####.#....####.#..#...##.###..#..#
-#..#....#.#....#....#..#....#.#..#.#.#.
-####...#..#....###..####....#.#..#.##..
-#..#..#...#....#....#..#....#.###..#.#.
-#..#.#....#....#....#..#.#..#.#.#..#.#.
-#..#.####.####.####.#..#..##..#..#.#..#
 "
         );
     }
@@ -1,26 +1,32 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day14.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day14;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day14 {
+    type Input = Instructions;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer> {
+        Ok(part1(instructions)?.into())
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer> {
+        Ok(part2(instructions)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let (polymer, rules) = input.split_once("\n\n").context("couldn't split input")?;
-    let mut polymer: Polymer = polymer.parse()?;
-    let rules: Rules = rules.parse()?;
+fn part1(instructions: &Instructions) -> Result<usize> {
+    let mut polymer = instructions.polymer.clone();
 
     for _ in 0..10 {
-        polymer.insert_pairs(&rules.0)?;
+        polymer.insert_pairs(&instructions.rules.0)?;
     }
 
     let occurrences = polymer.compute_occurrences();
@@ -37,13 +43,11 @@ fn part1(input: &str) -> Result<usize> {
     Ok(most_common_occurrences - least_common_occurrences)
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let (polymer, rules) = input.split_once("\n\n").context("couldn't split input")?;
-    let mut polymer: Polymer = polymer.parse()?;
-    let rules: Rules = rules.parse()?;
+fn part2(instructions: &Instructions) -> Result<usize> {
+    let mut polymer = instructions.polymer.clone();
 
     for _ in 0..40 {
-        polymer.insert_pairs(&rules.0)?;
+        polymer.insert_pairs(&instructions.rules.0)?;
     }
 
     let occurrences = polymer.compute_occurrences();
@@ -60,6 +64,24 @@ fn part2(input: &str) -> Result<usize> {
     Ok(most_common_occurrences - least_common_occurrences)
 }
 
+pub struct Instructions {
+    polymer: Polymer,
+    rules: Rules,
+}
+
+impl std::str::FromStr for Instructions {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (polymer, rules) = s.split_once("\n\n").context("couldn't split input")?;
+
+        Ok(Instructions {
+            polymer: polymer.parse()?,
+            rules: rules.parse()?,
+        })
+    }
+}
+
 struct Rules(HashMap<(u8, u8), u8>);
 
 impl std::str::FromStr for Rules {
@@ -84,7 +106,7 @@ impl std::str::FromStr for Rules {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Polymer {
     molecules: HashMap<(u8, u8), usize>,
     first: u8,
@@ -153,21 +175,14 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 1588);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 3247);
+        assert_eq!(part1(&Day14::parse(PROVIDED).unwrap()).unwrap(), 1588);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 2188189693529);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 4110568157153);
+        assert_eq!(
+            part2(&Day14::parse(PROVIDED).unwrap()).unwrap(),
+            2188189693529
+        );
     }
 }
@@ -1,30 +1,34 @@
-use std::fmt::Write;
 use std::ops::Range;
 
 use anyhow::{bail, Context, Result};
-use bitvec::prelude::*;
 
-const INPUT: &str = include_str!("../input/day16.txt");
+use aoc::{Answer, Solution};
+use bitvec::prelude::*;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day16;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day16 {
+    type Input = Packet;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
 
-fn part1(input: &str) -> Result<u64> {
-    let packet: Packet = input.parse()?;
+    fn part1(packet: &Self::Input) -> Result<Answer> {
+        Ok(part1(packet).into())
+    }
 
-    Ok(packet.version_sum())
+    fn part2(packet: &Self::Input) -> Result<Answer> {
+        Ok(part2(packet).into())
+    }
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let packet: Packet = input.parse()?;
+fn part1(packet: &Packet) -> u64 {
+    packet.version_sum()
+}
 
-    Ok(packet.value())
+fn part2(packet: &Packet) -> u64 {
+    packet.value()
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -34,7 +38,7 @@ enum PacketType {
 }
 
 #[derive(PartialEq, Eq, Debug)]
-struct Packet {
+pub struct Packet {
     version: u8,
     type_id: u8,
     bits_used: usize,
@@ -312,11 +316,6 @@ mod tests {
         assert_eq!(PART1_PROVIDED7.parse::<Packet>().unwrap().version_sum(), 31);
     }
 
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 925);
-    }
-
     const PART2_PROVIDED1: &str = "C200B40A82";
     const PART2_PROVIDED2: &str = "04005AC33890";
     const PART2_PROVIDED3: &str = "880086C3E88112";
@@ -328,18 +327,13 @@ mod tests {
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PART2_PROVIDED1).unwrap(), 3);
-        assert_eq!(part2(PART2_PROVIDED2).unwrap(), 54);
-        assert_eq!(part2(PART2_PROVIDED3).unwrap(), 7);
-        assert_eq!(part2(PART2_PROVIDED4).unwrap(), 9);
-        assert_eq!(part2(PART2_PROVIDED5).unwrap(), 1);
-        assert_eq!(part2(PART2_PROVIDED6).unwrap(), 0);
-        assert_eq!(part2(PART2_PROVIDED7).unwrap(), 0);
-        assert_eq!(part2(PART2_PROVIDED8).unwrap(), 1);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 342997120375);
+        assert_eq!(part2(&PART2_PROVIDED1.parse().unwrap()), 3);
+        assert_eq!(part2(&PART2_PROVIDED2.parse().unwrap()), 54);
+        assert_eq!(part2(&PART2_PROVIDED3.parse().unwrap()), 7);
+        assert_eq!(part2(&PART2_PROVIDED4.parse().unwrap()), 9);
+        assert_eq!(part2(&PART2_PROVIDED5.parse().unwrap()), 1);
+        assert_eq!(part2(&PART2_PROVIDED6.parse().unwrap()), 0);
+        assert_eq!(part2(&PART2_PROVIDED7.parse().unwrap()), 0);
+        assert_eq!(part2(&PART2_PROVIDED8.parse().unwrap()), 1);
     }
 }
@@ -6,27 +6,31 @@
 //!
 //! This already runs in 1ms though so... maybe later
 use std::cmp::Ordering;
-use std::fmt::Write;
 use std::ops::RangeInclusive;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day17.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day17;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day17 {
+    type Input = TargetArea;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse().context("couldn't parse input to target area")
+    }
 
-fn part1(input: &str) -> Result<isize> {
-    let area: TargetArea = input
-        .parse()
-        .context("couldn't parse input to target area")?;
+    fn part1(area: &Self::Input) -> Result<Answer> {
+        Ok(part1(area)?.into())
+    }
 
+    fn part2(area: &Self::Input) -> Result<Answer> {
+        Ok(part2(area).into())
+    }
+}
+
+fn part1(area: &TargetArea) -> Result<isize> {
     let min_x_vel = if area.min_x() > 0 {
         (0..).find(|x| ((x * (x + 1)) / 2) >= area.min_x()).unwrap()
     } else if area.max_x() < 0 {
@@ -54,16 +58,12 @@ fn part1(input: &str) -> Result<isize> {
 
     (min_x_vel..=max_x_vel)
         .flat_map(|x_vel| (min_y_vel..=max_y_vel).map(move |y_vel| (x_vel, y_vel)))
-        .filter_map(|(x_vel, y_vel)| throw(x_vel, y_vel, &area))
+        .filter_map(|(x_vel, y_vel)| throw(x_vel, y_vel, area))
         .max()
         .context("couldn't find any trajectory")
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let area: TargetArea = input
-        .parse()
-        .context("couldn't parse input to target area")?;
-
+fn part2(area: &TargetArea) -> usize {
     let min_x_vel = if area.min_x() > 0 {
         (0..).find(|x| ((x * (x + 1)) / 2) >= area.min_x()).unwrap()
     } else if area.max_x() < 0 {
@@ -89,10 +89,10 @@ fn part2(input: &str) -> Result<usize> {
     let min_y_vel = area.min_y();
     let max_y_vel = area.min_y().abs();
 
-    Ok((min_x_vel..=max_x_vel)
+    (min_x_vel..=max_x_vel)
         .flat_map(|x_vel| (min_y_vel..=max_y_vel).map(move |y_vel| (x_vel, y_vel)))
-        .filter_map(|(x_vel, y_vel)| throw(x_vel, y_vel, &area))
-        .count())
+        .filter_map(|(x_vel, y_vel)| throw(x_vel, y_vel, area))
+        .count()
 }
 
 fn throw(mut xvel: isize, mut yvel: isize, area: &TargetArea) -> Option<isize> {
@@ -136,7 +136,7 @@ fn throw(mut xvel: isize, mut yvel: isize, area: &TargetArea) -> Option<isize> {
     }
 }
 
-struct TargetArea {
+pub struct TargetArea {
     x_range: RangeInclusive<isize>,
     y_range: RangeInclusive<isize>,
 }
@@ -193,21 +193,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 45);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 4186);
+        assert_eq!(part1(&PROVIDED.parse().unwrap()).unwrap(), 45);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 112);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2709);
+        assert_eq!(part2(&PROVIDED.parse().unwrap()), 112);
     }
 }
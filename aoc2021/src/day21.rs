@@ -1,36 +1,48 @@
 use std::collections::HashMap;
-use std::fmt::Write;
 use std::iter;
 use std::ops::RangeInclusive;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day21.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day21;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day21 {
+    type Input = (PlayerPos, PlayerPos);
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_positions(input)
+    }
+
+    fn part1((player1_pos, player2_pos): &Self::Input) -> Result<Answer> {
+        Ok(part1(*player1_pos, *player2_pos).into())
+    }
+
+    fn part2((player1_pos, player2_pos): &Self::Input) -> Result<Answer> {
+        Ok(part2(*player1_pos, *player2_pos).into())
+    }
 }
 
-fn part1(input: &str) -> Result<usize> {
+fn parse_positions(input: &str) -> Result<(PlayerPos, PlayerPos)> {
     let mut lines = input.lines();
-    let mut player1_pos: PlayerPos = lines
+    let player1_pos = lines
         .next()
         .and_then(|line| line.trim().strip_prefix("Player 1 starting position: "))
         .and_then(|pos| pos.parse().ok())
         .map(PlayerPos::new)
         .context("couldn't find player 1 pos")?;
-    let mut player2_pos: PlayerPos = lines
+    let player2_pos = lines
         .next()
         .and_then(|line| line.trim().strip_prefix("Player 2 starting position: "))
         .and_then(|pos| pos.parse().ok())
         .map(PlayerPos::new)
         .context("couldn't find player 2 pos")?;
 
+    Ok((player1_pos, player2_pos))
+}
+
+fn part1(mut player1_pos: PlayerPos, mut player2_pos: PlayerPos) -> usize {
     let mut player1_score = 0;
     let mut player2_score = 0;
     let mut dice = DeterministicDice::new();
@@ -47,28 +59,14 @@ fn part1(input: &str) -> Result<usize> {
 
     let loser_score = player1_score;
 
-    Ok(loser_score * dice.rolls())
+    loser_score * dice.rolls()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut lines = input.lines();
-    let player1_pos: PlayerPos = lines
-        .next()
-        .and_then(|line| line.trim().strip_prefix("Player 1 starting position: "))
-        .and_then(|pos| pos.parse().ok())
-        .map(PlayerPos::new)
-        .context("couldn't find player 1 pos")?;
-    let player2_pos: PlayerPos = lines
-        .next()
-        .and_then(|line| line.trim().strip_prefix("Player 2 starting position: "))
-        .and_then(|pos| pos.parse().ok())
-        .map(PlayerPos::new)
-        .context("couldn't find player 2 pos")?;
-
+fn part2(player1_pos: PlayerPos, player2_pos: PlayerPos) -> usize {
     let (player1_score, player2_score) =
         quantum_dice_game(player1_pos, player2_pos, 0, 0, &mut HashMap::new());
 
-    Ok(player1_score.max(player2_score))
+    player1_score.max(player2_score)
 }
 
 type Cache = HashMap<(PlayerPos, PlayerPos, usize, usize), (usize, usize)>;
@@ -112,7 +110,7 @@ fn quantum_dice_game(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct PlayerPos(usize);
+pub struct PlayerPos(usize);
 
 impl PlayerPos {
     fn new(pos: usize) -> Self {
@@ -163,21 +161,13 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 739785);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 908595);
+        let (player1_pos, player2_pos) = parse_positions(PROVIDED).unwrap();
+        assert_eq!(part1(player1_pos, player2_pos), 739785);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 444356092776315);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 91559198282731);
+        let (player1_pos, player2_pos) = parse_positions(PROVIDED).unwrap();
+        assert_eq!(part2(player1_pos, player2_pos), 444356092776315);
     }
 }
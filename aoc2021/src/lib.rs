@@ -17,5 +17,6 @@ pub mod day14;
 pub mod day15;
 pub mod day16;
 pub mod day17;
-
 pub mod day21;
+
+aoc::year!();
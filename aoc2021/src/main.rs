@@ -1,48 +0,0 @@
-use anyhow::Result;
-
-use aoc::DayFunc;
-
-use aoc2021::day01;
-use aoc2021::day02;
-use aoc2021::day03;
-use aoc2021::day04;
-use aoc2021::day05;
-use aoc2021::day06;
-use aoc2021::day07;
-use aoc2021::day08;
-use aoc2021::day09;
-use aoc2021::day10;
-use aoc2021::day11;
-use aoc2021::day12;
-use aoc2021::day13;
-use aoc2021::day14;
-use aoc2021::day15;
-use aoc2021::day16;
-use aoc2021::day17;
-
-use aoc2021::day21;
-
-fn main() -> Result<()> {
-    let days: &[DayFunc] = &[
-        day01::run,
-        day02::run,
-        day03::run,
-        day04::run,
-        day05::run,
-        day06::run,
-        day07::run,
-        day08::run,
-        day09::run,
-        day10::run,
-        day11::run,
-        day12::run,
-        day13::run,
-        day14::run,
-        day15::run,
-        day16::run,
-        day17::run,
-        day21::run,
-    ];
-
-    aoc::run(days)
-}
@@ -1,25 +1,28 @@
 use std::collections::BinaryHeap;
-use std::fmt::Write;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day01.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day01;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day01 {
+    type Input = Vec<Inventory>;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.split("\n\n").map(str::parse).collect()
+    }
 
-fn part1(input: &str) -> Result<u64> {
-    let inventories = input
-        .split("\n\n")
-        .map(str::parse::<Inventory>)
-        .collect::<Result<Vec<_>>>()?;
+    fn part1(inventories: &Self::Input) -> Result<Answer> {
+        Ok(part1(inventories)?.into())
+    }
 
+    fn part2(inventories: &Self::Input) -> Result<Answer> {
+        Ok(part2(inventories).into())
+    }
+}
+
+fn part1(inventories: &[Inventory]) -> Result<u64> {
     inventories
         .iter()
         .map(Inventory::total_calories)
@@ -27,17 +30,17 @@ fn part1(input: &str) -> Result<u64> {
         .context("inventory list was empty")
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let inventories = input
-        .split("\n\n")
-        .map(|line| line.parse::<Inventory>().map(|inv| inv.total_calories()))
-        .collect::<Result<BinaryHeap<_>>>()?;
+fn part2(inventories: &[Inventory]) -> u64 {
+    let calories = inventories
+        .iter()
+        .map(Inventory::total_calories)
+        .collect::<BinaryHeap<_>>();
 
-    Ok(inventories.iter().take(3).sum())
+    calories.iter().take(3).sum()
 }
 
 #[derive(Debug)]
-struct Inventory(Vec<u64>);
+pub struct Inventory(Vec<u64>);
 
 impl std::str::FromStr for Inventory {
     type Err = anyhow::Error;
@@ -65,21 +68,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 24000);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 68923);
+        assert_eq!(part1(&Day01::parse(PROVIDED).unwrap()).unwrap(), 24000);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 45000);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 200044);
+        assert_eq!(part2(&Day01::parse(PROVIDED).unwrap()), 45000);
     }
 }
@@ -1,36 +1,48 @@
-use std::fmt::Write;
-
 use anyhow::{bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day02.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day02;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day02 {
+    type Input = StrategyGuide;
 
-    Ok(res)
-}
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
 
-fn part1(input: &str) -> Result<u64> {
-    let mut score = 0;
-    for line in input.lines() {
-        let round: RoundPart1 = line.parse()?;
-        score += round.score();
+    fn part1(guide: &Self::Input) -> Result<Answer> {
+        Ok(part1(guide).into())
     }
 
-    Ok(score)
+    fn part2(guide: &Self::Input) -> Result<Answer> {
+        Ok(part2(guide).into())
+    }
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let mut score = 0;
-    for line in input.lines() {
-        let round: RoundPart2 = line.parse()?;
-        score += round.score();
-    }
+fn part1(guide: &StrategyGuide) -> u64 {
+    guide.part1.iter().map(RoundPart1::score).sum()
+}
+
+fn part2(guide: &StrategyGuide) -> u64 {
+    guide.part2.iter().map(RoundPart2::score).sum()
+}
+
+/// The second column means something different in each part, so the guide is read both ways.
+pub struct StrategyGuide {
+    part1: Vec<RoundPart1>,
+    part2: Vec<RoundPart2>,
+}
+
+impl std::str::FromStr for StrategyGuide {
+    type Err = anyhow::Error;
 
-    Ok(score)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            part1: s.lines().map(str::parse).collect::<Result<_>>()?,
+            part2: s.lines().map(str::parse).collect::<Result<_>>()?,
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -182,21 +194,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 15);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 11150);
+        assert_eq!(part1(&PROVIDED.parse().unwrap()), 15);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 12);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 8295);
+        assert_eq!(part2(&PROVIDED.parse().unwrap()), 12);
     }
 }
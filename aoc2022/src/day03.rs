@@ -1,22 +1,30 @@
-use std::{collections::BTreeSet, fmt::Write, str::FromStr};
+use std::{collections::BTreeSet, str::FromStr};
 
 use anyhow::{bail, Context, Result};
 
-const INPUT: &str = include_str!("../input/day03.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+pub struct Day03;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+impl Solution for Day03 {
+    type Input = Rucksacks;
 
-    Ok(res)
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<Answer> {
+        Ok(part1(rucksacks)?.into())
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<Answer> {
+        Ok(part2(rucksacks)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<u64> {
+fn part1(rucksacks: &Rucksacks) -> Result<u64> {
     let mut priorities = 0;
-    for line in input.lines() {
-        let rucksack = RucksackSplit::from_str(line)?;
+    for rucksack in &rucksacks.split {
         let item = rucksack
             .find_duplicate()
             .context("rucksack didn't have any duplicate!")?;
@@ -26,14 +34,10 @@ fn part1(input: &str) -> Result<u64> {
     Ok(priorities)
 }
 
-fn part2(input: &str) -> Result<u64> {
+fn part2(rucksacks: &Rucksacks) -> Result<u64> {
     let mut priorities = 0;
-    let mut lines = input.lines();
-    while let (Some(first), Some(second), Some(third)) = (lines.next(), lines.next(), lines.next())
-    {
-        let first = Rucksack::from_str(first)?;
-        let second = Rucksack::from_str(second)?;
-        let third = Rucksack::from_str(third)?;
+    for group in rucksacks.whole.chunks_exact(3) {
+        let (first, second, third) = (&group[0], &group[1], &group[2]);
 
         let priority = first
             .items
@@ -51,6 +55,26 @@ fn part2(input: &str) -> Result<u64> {
     Ok(priorities)
 }
 
+/// Part 1 looks at the two compartments of each rucksack, part 2 at whole rucksacks.
+pub struct Rucksacks {
+    split: Vec<RucksackSplit>,
+    whole: Vec<Rucksack>,
+}
+
+impl FromStr for Rucksacks {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            split: s
+                .lines()
+                .map(RucksackSplit::from_str)
+                .collect::<Result<_>>()?,
+            whole: s.lines().map(Rucksack::from_str).collect::<Result<_>>()?,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 struct Item(u8);
 
@@ -144,21 +168,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 157);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 8018);
+        assert_eq!(part1(&PROVIDED.parse().unwrap()).unwrap(), 157);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 70);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2518);
+        assert_eq!(part2(&PROVIDED.parse().unwrap()).unwrap(), 70);
     }
 }
@@ -3,3 +3,5 @@
 pub mod day01;
 pub mod day02;
 pub mod day03;
+
+aoc::year!();
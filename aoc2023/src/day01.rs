@@ -1,21 +1,28 @@
-use std::fmt::Write;
-
 use anyhow::Result;
 
-const INPUT: &str = include_str!("../input/day01.txt");
+use aoc::{Answer, Solution};
+
+pub struct Day01;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
+impl Solution for Day01 {
+    type Input = Vec<String>;
 
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        Ok(part1(lines)?.into())
+    }
 
-    Ok(res)
+    fn part2(lines: &Self::Input) -> Result<Answer> {
+        Ok(part2(lines)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let digit_list = input
-        .lines()
+fn part1(lines: &[String]) -> Result<u64> {
+    let digit_list = lines
+        .iter()
         .map(|s| s.chars().filter(char::is_ascii_digit).collect::<String>());
 
     Ok(digit_list
@@ -28,7 +35,7 @@ fn part1(input: &str) -> Result<u64> {
         .sum())
 }
 
-fn part2(_input: &str) -> Result<u64> {
+fn part2(_lines: &[String]) -> Result<u64> {
     todo!()
 }
 
@@ -40,11 +47,6 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 142);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 54697);
+        assert_eq!(part1(&Day01::parse(PROVIDED).unwrap()).unwrap(), 142);
     }
 }
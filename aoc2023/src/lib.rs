@@ -1 +1,3 @@
 pub mod day01;
+
+aoc::year!();
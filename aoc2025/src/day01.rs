@@ -1,18 +1,30 @@
-use std::{fmt::Write, str::FromStr};
+use std::str::FromStr;
 
 use anyhow::{Result, bail};
 
-const INPUT: &str = include_str!("../input/day01.txt");
+use aoc::{Answer, Solution};
+
 const DIAL_SIZE: u16 = 100;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
-    Ok(res)
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Rotation>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(Rotation::from_str).collect()
+    }
+
+    fn part1(rotations: &Self::Input) -> Result<Answer> {
+        Ok(part1(rotations).into())
+    }
+
+    fn part2(rotations: &Self::Input) -> Result<Answer> {
+        Ok(part2(rotations).into())
+    }
 }
 
-enum Rotation {
+pub enum Rotation {
     Left(u16),
     Right(u16),
 }
@@ -72,13 +84,8 @@ impl Rotation {
     }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let rotations = input
-        .lines()
-        .map(Rotation::from_str)
-        .collect::<Result<Vec<Rotation>>>()?;
-
-    let res = rotations
+fn part1(rotations: &[Rotation]) -> usize {
+    rotations
         .iter()
         .fold((50, 0), |(state, mut count), rot| {
             let new_state = rot.apply(state);
@@ -87,24 +94,17 @@ fn part1(input: &str) -> Result<usize> {
             }
             (new_state, count)
         })
-        .1;
-    Ok(res)
+        .1
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let rotations = input
-        .lines()
-        .map(Rotation::from_str)
-        .collect::<Result<Vec<Rotation>>>()?;
-
-    let res = rotations
+fn part2(rotations: &[Rotation]) -> usize {
+    rotations
         .iter()
         .fold((50, 0), |(state, count), rot| {
             let (new_state, zeroes) = rot.apply_and_count_zeroes(state);
             (new_state, count + zeroes)
         })
-        .1;
-    Ok(res)
+        .1
 }
 
 #[cfg(test)]
@@ -115,21 +115,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 3);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1092);
+        assert_eq!(part1(&Day01::parse(PROVIDED).unwrap()), 3);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 6);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 6616);
+        assert_eq!(part2(&Day01::parse(PROVIDED).unwrap()), 6);
     }
 }
@@ -1,16 +1,27 @@
 use anyhow::{Context, Result, anyhow};
-use std::{fmt::Write, ops::RangeInclusive, str::FromStr};
+use aoc::{Answer, Solution};
+use std::{ops::RangeInclusive, str::FromStr};
 
-const INPUT: &str = include_str!("../input/day02.txt");
+pub struct Day02;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
-    Ok(res)
+impl Solution for Day02 {
+    type Input = Vec<IdRange>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.split(',').map(IdRange::from_str).collect()
+    }
+
+    fn part1(ranges: &Self::Input) -> Result<Answer> {
+        Ok(part1(ranges).into())
+    }
+
+    fn part2(ranges: &Self::Input) -> Result<Answer> {
+        Ok(part2(ranges).into())
+    }
 }
 
-struct IdRange(RangeInclusive<u64>);
+#[derive(Clone)]
+pub struct IdRange(RangeInclusive<u64>);
 
 impl FromStr for IdRange {
     type Err = anyhow::Error;
@@ -55,16 +66,11 @@ fn is_repeated_twice(num: &u64) -> bool {
     *num / mask == *num % mask
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let ranges = input
-        .split(',')
-        .map(IdRange::from_str)
-        .collect::<Result<Vec<_>>>()?;
-
-    Ok(ranges
-        .into_iter()
-        .map(|range| range.filter(is_repeated_twice).sum::<u64>())
-        .sum())
+fn part1(ranges: &[IdRange]) -> u64 {
+    ranges
+        .iter()
+        .map(|range| range.clone().filter(is_repeated_twice).sum::<u64>())
+        .sum()
 }
 
 fn has_identical_chunks(num: &u64, chunk_size: usize) -> bool {
@@ -103,16 +109,16 @@ fn is_repeated_at_least_twice(num: &u64) -> bool {
         })
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let ranges = input
-        .split(',')
-        .map(IdRange::from_str)
-        .collect::<Result<Vec<_>>>()?;
-
-    Ok(ranges
-        .into_iter()
-        .map(|range| range.filter(is_repeated_at_least_twice).sum::<u64>())
-        .sum())
+fn part2(ranges: &[IdRange]) -> u64 {
+    ranges
+        .iter()
+        .map(|range| {
+            range
+                .clone()
+                .filter(is_repeated_at_least_twice)
+                .sum::<u64>()
+        })
+        .sum()
 }
 
 #[cfg(test)]
@@ -139,12 +145,7 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 1227775554);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 26255179562);
+        assert_eq!(part1(&Day02::parse(PROVIDED).unwrap()), 1227775554);
     }
 
     #[test]
@@ -170,11 +171,6 @@ mod tests {
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 4174379265);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 31680313976);
+        assert_eq!(part2(&Day02::parse(PROVIDED).unwrap()), 4174379265);
     }
 }
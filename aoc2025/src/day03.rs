@@ -1,14 +1,35 @@
-use std::{cmp, fmt::Write};
+use std::cmp;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day03.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
-    Ok(res)
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(parse_bank).collect()
+    }
+
+    fn part1(banks: &Self::Input) -> Result<Answer> {
+        Ok(part1(banks)?.into())
+    }
+
+    fn part2(banks: &Self::Input) -> Result<Answer> {
+        Ok(part2(banks)?.into())
+    }
+}
+
+fn parse_bank(line: &str) -> Result<Vec<u8>> {
+    line.chars()
+        .map(|c| {
+            c.to_digit(10)
+                .map(|digit| digit as u8)
+                .with_context(|| format!("couldn't parse battery `{}' in bank `{}'", c, line))
+        })
+        .collect()
 }
 
 fn find_max_digit(bank: &[u8]) -> Option<(usize, u8)> {
@@ -30,33 +51,27 @@ fn compute_max_joltage(mut bank: &[u8], batteries: usize) -> Result<u64> {
         let (idx, digit) = find_max_digit(&bank[..(bank.len() - not_in_search)])
             .context("couldn't find max digit in bank")?;
 
-        let digit = (digit - b'0') as u64;
-        joltage = joltage * 10 + digit;
+        joltage = joltage * 10 + digit as u64;
         bank = &bank[(idx + 1)..];
     }
     Ok(joltage)
 }
 
-fn part1(input: &str) -> Result<u64> {
+fn total_joltage(banks: &[Vec<u8>], batteries: usize) -> Result<u64> {
     let mut sum = 0;
-    for line in input.lines() {
-        let joltage = compute_max_joltage(line.as_bytes(), 2)
-            .with_context(|| format!("couldn't compute joltage for bank `{}'", line))?;
-        sum += joltage;
+    for bank in banks {
+        sum += compute_max_joltage(bank, batteries)?;
     }
 
     Ok(sum)
 }
 
-fn part2(input: &str) -> Result<u64> {
-    let mut sum = 0;
-    for line in input.lines() {
-        let joltage = compute_max_joltage(line.as_bytes(), 12)
-            .with_context(|| format!("couldn't compute joltage for bank `{}'", line))?;
-        sum += joltage;
-    }
+fn part1(banks: &[Vec<u8>]) -> Result<u64> {
+    total_joltage(banks, 2)
+}
 
-    Ok(sum)
+fn part2(banks: &[Vec<u8>]) -> Result<u64> {
+    total_joltage(banks, 12)
 }
 
 #[cfg(test)]
@@ -68,38 +83,34 @@ mod tests {
     #[test]
     fn part1_provided() {
         assert_eq!(
-            compute_max_joltage("987654321111111".as_bytes(), 2).ok(),
+            compute_max_joltage(&parse_bank("987654321111111").unwrap(), 2).ok(),
             Some(98)
         );
         assert_eq!(
-            compute_max_joltage("811111111111119".as_bytes(), 2).ok(),
+            compute_max_joltage(&parse_bank("811111111111119").unwrap(), 2).ok(),
             Some(89)
         );
         assert_eq!(
-            compute_max_joltage("234234234234278".as_bytes(), 2).ok(),
+            compute_max_joltage(&parse_bank("234234234234278").unwrap(), 2).ok(),
             Some(78)
         );
         assert_eq!(
-            compute_max_joltage("818181911112111".as_bytes(), 2).ok(),
+            compute_max_joltage(&parse_bank("818181911112111").unwrap(), 2).ok(),
             Some(92)
         );
         // added this test to check for min + Reverse usage
-        assert_eq!(compute_max_joltage("9892".as_bytes(), 2).ok(), Some(99));
-        assert_eq!(part1(PROVIDED).unwrap(), 357);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 17034);
+        assert_eq!(
+            compute_max_joltage(&parse_bank("9892").unwrap(), 2).ok(),
+            Some(99)
+        );
+        assert_eq!(part1(&Day03::parse(PROVIDED).unwrap()).unwrap(), 357);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 3121910778619);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 168798209663590);
+        assert_eq!(
+            part2(&Day03::parse(PROVIDED).unwrap()).unwrap(),
+            3121910778619
+        );
     }
 }
@@ -1,89 +1,55 @@
-use std::{fmt::Write, str::FromStr};
+use std::str::FromStr;
 
 use anyhow::{Result, bail};
 
-const INPUT: &str = include_str!("../input/day04.txt");
+use aoc::grid::Point;
+use aoc::{Answer, Grid, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
-    Ok(res)
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = PaperRollsMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        Ok(part1(grid).into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        Ok(part2(grid).into())
+    }
 }
 
-struct PaperRollsMap(Vec<Vec<bool>>);
+#[derive(Clone)]
+pub struct PaperRollsMap(Grid<bool>);
 
 impl FromStr for PaperRollsMap {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let mut grid = Vec::new();
-
-        let mut width = None;
-        for line in s.lines() {
-            match width {
-                Some(size) => assert_eq!(size, line.len()),
-                None => width = Some(line.len()),
-            }
-
-            grid.push(
-                line.chars()
-                    .map(|c| match c {
-                        '@' => Ok(true),
-                        '.' => Ok(false),
-                        _ => bail!("unknown character `{}' while parsing grid", c),
-                    })
-                    .collect::<Result<Vec<_>>>()?,
-            );
-        }
+        let grid = Grid::parse_with(s, |c| match c {
+            '@' => Ok(true),
+            '.' => Ok(false),
+            _ => bail!("unknown character `{}' while parsing grid", c),
+        })?;
 
         Ok(Self(grid))
     }
 }
 
 impl PaperRollsMap {
-    fn width(&self) -> usize {
-        self.0[0].len()
-    }
-
-    fn len(&self) -> usize {
-        self.0.len()
-    }
-
     fn count_accessible_rolls(&self) -> usize {
-        let mut count = 0;
-        for y in 0..self.len() {
-            for x in 0..self.width() {
-                if self.0[y][x] && self.roll_is_accessible(x, y) {
-                    count += 1
-                }
-            }
-        }
-        count
+        self.0
+            .iter()
+            .filter(|&(point, &roll)| roll && self.roll_is_accessible(point))
+            .count()
     }
 
-    fn roll_is_accessible(&self, x: usize, y: usize) -> bool {
-        let mut count = 0;
-        for dy in [-1, 0, 1] {
-            match y.checked_add_signed(dy) {
-                None => continue,
-                Some(yval) if yval == self.len() => continue,
-                Some(yval) => {
-                    for dx in [-1, 0, 1] {
-                        match x.checked_add_signed(dx) {
-                            None => continue,
-                            Some(xval) if xval == self.width() => continue,
-                            Some(xval) => {
-                                if self.0[yval][xval] {
-                                    count += 1
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        count <= 4
+    fn roll_is_accessible(&self, point: Point) -> bool {
+        self.0.neighbours8(point).filter(|&p| self.0[p]).count() < 4
     }
 
     fn count_removable_rolls(&mut self) -> usize {
@@ -91,12 +57,10 @@ impl PaperRollsMap {
         loop {
             let mut cur_loop_count = 0;
             let mut copy = PaperRollsMap(self.0.clone());
-            for y in 0..self.len() {
-                for x in 0..self.width() {
-                    if self.0[y][x] && self.roll_is_accessible(x, y) {
-                        copy.0[y][x] = false;
-                        cur_loop_count += 1;
-                    }
+            for (point, &roll) in self.0.iter() {
+                if roll && self.roll_is_accessible(point) {
+                    copy.0[point] = false;
+                    cur_loop_count += 1;
                 }
             }
 
@@ -111,16 +75,12 @@ impl PaperRollsMap {
     }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let grid: PaperRollsMap = input.parse()?;
-
-    Ok(grid.count_accessible_rolls())
+fn part1(grid: &PaperRollsMap) -> usize {
+    grid.count_accessible_rolls()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut grid: PaperRollsMap = input.parse()?;
-
-    Ok(grid.count_removable_rolls())
+fn part2(grid: &PaperRollsMap) -> usize {
+    grid.clone().count_removable_rolls()
 }
 
 #[cfg(test)]
@@ -131,21 +91,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 13);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1393);
+        assert_eq!(part1(&PROVIDED.parse().unwrap()), 13);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 43);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 8643);
+        assert_eq!(part2(&PROVIDED.parse().unwrap()), 43);
     }
 }
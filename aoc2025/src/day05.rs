@@ -1,67 +1,80 @@
-use std::{
-    fmt::Write,
-    ops::{Bound, RangeBounds},
-};
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+use std::str::FromStr;
 
 use anyhow::{Context, Result};
 
-const INPUT: &str = include_str!("../input/day05.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
-    Ok(res)
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(inventory: &Self::Input) -> Result<Answer> {
+        Ok(part1(inventory).into())
+    }
+
+    fn part2(inventory: &Self::Input) -> Result<Answer> {
+        Ok(part2(inventory)?.into())
+    }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let mut fresh_id_ranges = Vec::new();
-    let mut available_ids = Vec::new();
+pub struct Inventory {
+    fresh_id_ranges: Vec<RangeInclusive<u64>>,
+    available_ids: Vec<u64>,
+}
+
+impl FromStr for Inventory {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut fresh_id_ranges = Vec::new();
+        let mut available_ids = Vec::new();
 
-    let mut lines = input.lines();
-    for line in lines.by_ref() {
-        if line.is_empty() {
-            break;
+        let mut lines = s.lines();
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            let (left, right) = line
+                .split_once('-')
+                .with_context(|| format!("failed to split range `{}' on `-'", line))?;
+            let (left, right) = (left.parse::<u64>()?, right.parse::<u64>()?);
+            let range = left..=right;
+            fresh_id_ranges.push(range);
         }
-        let (left, right) = line
-            .split_once('-')
-            .with_context(|| format!("failed to split range `{}' on `-'", line))?;
-        let (left, right) = (left.parse::<u64>()?, right.parse::<u64>()?);
-        let range = left..=right;
-        fresh_id_ranges.push(range);
-    }
 
-    for line in lines {
-        available_ids.push(line.parse::<u64>()?);
+        for line in lines {
+            available_ids.push(line.parse::<u64>()?);
+        }
+
+        Ok(Inventory {
+            fresh_id_ranges,
+            available_ids,
+        })
     }
+}
 
+fn part1(inventory: &Inventory) -> u64 {
     let mut count = 0;
-    for id in available_ids {
-        for range in &fresh_id_ranges {
-            if range.contains(&id) {
+    for id in &inventory.available_ids {
+        for range in &inventory.fresh_id_ranges {
+            if range.contains(id) {
                 count += 1;
                 break;
             }
         }
     }
 
-    Ok(count)
+    count
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let mut fresh_id_ranges = Vec::new();
-
-    for line in input.lines() {
-        if line.is_empty() {
-            break;
-        }
-        let (left, right) = line
-            .split_once('-')
-            .with_context(|| format!("failed to split range `{}' on `-'", line))?;
-        let (left, right) = (left.parse::<u64>()?, right.parse::<u64>()?);
-        let range = left..=right;
-        fresh_id_ranges.push(range);
-    }
+fn part2(inventory: &Inventory) -> Result<usize> {
+    let mut fresh_id_ranges = inventory.fresh_id_ranges.clone();
 
     // sort all ranges to prepare for merging of overlapping ranges
     let mut new_ranges = Vec::new();
@@ -107,21 +120,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 3);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 509);
+        assert_eq!(part1(&PROVIDED.parse().unwrap()), 3);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 14);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 336790092076620);
+        assert_eq!(part2(&PROVIDED.parse().unwrap()).unwrap(), 14);
     }
 }
@@ -1,14 +1,25 @@
-use std::{fmt::Write, str::FromStr};
+use std::str::FromStr;
 
 use anyhow::{Context, Result, bail};
 
-const INPUT: &str = include_str!("../input/day06.txt");
+use aoc::{Answer, Solution};
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
-    Ok(res)
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(worksheet: &Self::Input) -> Result<Answer> {
+        Ok(part1(worksheet)?.into())
+    }
+
+    fn part2(worksheet: &Self::Input) -> Result<Answer> {
+        Ok(part2(worksheet)?.into())
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -44,25 +55,45 @@ impl MathProblem {
     }
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let mut numbers: Vec<Vec<u64>> = Vec::new();
-    let mut ops = Vec::new();
-
-    for line in input.lines() {
-        let line = line.trim();
-        if line
-            .chars()
-            .next()
-            .context("got an empty line")?
-            .is_ascii_punctuation()
-        {
-            ops = line
-                .split_ascii_whitespace()
-                .map(|c| c.parse::<MathOp>())
-                .collect::<Result<_>>()?;
-            break;
+/// The rows of numbers are kept as they are: each part reads them its own way.
+pub struct Worksheet {
+    rows: Vec<String>,
+    ops: Vec<MathOp>,
+}
+
+impl FromStr for Worksheet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut rows = Vec::new();
+        let mut ops = Vec::new();
+
+        for line in s.lines() {
+            if line
+                .trim_start()
+                .chars()
+                .next()
+                .context("got an empty line")?
+                .is_ascii_punctuation()
+            {
+                ops = line
+                    .split_ascii_whitespace()
+                    .map(|c| c.parse::<MathOp>())
+                    .collect::<Result<_>>()?;
+                break;
+            }
+
+            rows.push(line.to_string());
         }
 
+        Ok(Worksheet { rows, ops })
+    }
+}
+
+fn part1(worksheet: &Worksheet) -> Result<u64> {
+    let mut numbers: Vec<Vec<u64>> = Vec::new();
+
+    for line in &worksheet.rows {
         let split_line = line.trim().split_ascii_whitespace();
         let width = split_line.clone().count();
         if !numbers.is_empty() && numbers.len() != width {
@@ -83,32 +114,20 @@ fn part1(input: &str) -> Result<u64> {
         }
     }
 
-    Ok(ops
-        .into_iter()
+    Ok(worksheet
+        .ops
+        .iter()
+        .copied()
         .zip(numbers)
         .map(|(op, numbers)| MathProblem { numbers, op })
         .map(|problem| problem.compute())
         .sum())
 }
 
-fn part2(input: &str) -> Result<u64> {
+fn part2(worksheet: &Worksheet) -> Result<u64> {
     let mut numbers: Vec<Vec<u8>> = Vec::new();
-    let mut ops = Vec::new();
-
-    for line in input.lines() {
-        if line
-            .chars()
-            .next()
-            .context("got an empty line")?
-            .is_ascii_punctuation()
-        {
-            ops = line
-                .split_ascii_whitespace()
-                .map(|c| c.parse::<MathOp>())
-                .collect::<Result<_>>()?;
-            break;
-        }
 
+    for line in &worksheet.rows {
         let line = line.as_bytes().to_vec();
         if !numbers.is_empty() && numbers[0].len() != line.len() {
             bail!(
@@ -155,16 +174,18 @@ fn part2(input: &str) -> Result<u64> {
     if !cur_problem_numbers.is_empty() {
         parsed_numbers.push(cur_problem_numbers);
     }
-    if parsed_numbers.len() != ops.len() {
+    if parsed_numbers.len() != worksheet.ops.len() {
         bail!(
             "got a different number of number sets ({}) and operators ({})",
             parsed_numbers.len(),
-            ops.len()
+            worksheet.ops.len()
         );
     }
 
-    Ok(ops
-        .into_iter()
+    Ok(worksheet
+        .ops
+        .iter()
+        .copied()
         .zip(parsed_numbers)
         .map(|(op, numbers)| MathProblem { numbers, op })
         .map(|problem| problem.compute())
@@ -179,21 +200,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 4277556);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 5316572080628);
+        assert_eq!(part1(&PROVIDED.parse().unwrap()).unwrap(), 4277556);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 3263827);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 11299263623062);
+        assert_eq!(part2(&PROVIDED.parse().unwrap()).unwrap(), 3263827);
     }
 }
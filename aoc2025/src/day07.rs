@@ -1,17 +1,27 @@
 use anyhow::{Result, anyhow, bail};
+use aoc::grid::Point;
+use aoc::{Answer, Grid, Solution};
 use std::{
     collections::{HashMap, hash_map::Entry},
-    fmt::Write,
     str::FromStr,
 };
 
-const INPUT: &str = include_str!("../input/day07.txt");
+pub struct Day07;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
-    Ok(res)
+impl Solution for Day07 {
+    type Input = ManifoldDiagram;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(diagram: &Self::Input) -> Result<Answer> {
+        Ok(part1(diagram).into())
+    }
+
+    fn part2(diagram: &Self::Input) -> Result<Answer> {
+        Ok(part2(diagram).into())
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -21,50 +31,40 @@ enum DiagramCell {
     Splitter,
 }
 
-impl FromStr for DiagramCell {
-    type Err = anyhow::Error;
+impl TryFrom<char> for DiagramCell {
+    type Error = anyhow::Error;
 
-    fn from_str(s: &str) -> Result<Self> {
-        match s {
-            "S" => Ok(DiagramCell::Source),
-            "." => Ok(DiagramCell::EmptySpace),
-            "^" => Ok(DiagramCell::Splitter),
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            'S' => Ok(DiagramCell::Source),
+            '.' => Ok(DiagramCell::EmptySpace),
+            '^' => Ok(DiagramCell::Splitter),
             _ => Err(anyhow!(
-                "cannot parse unexpected string `{}' as DiagramCell",
-                s
+                "cannot parse unexpected char `{}' as DiagramCell",
+                c
             )),
         }
     }
 }
 
 #[derive(Debug, Clone)]
-struct ManifoldDiagram {
-    grid: Vec<Vec<DiagramCell>>,
-    source_coords: (usize, usize),
+pub struct ManifoldDiagram {
+    grid: Grid<DiagramCell>,
+    source_coords: Point,
 }
 
 impl FromStr for ManifoldDiagram {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let mut grid = Vec::new();
-        let mut source_coords = None;
-        for (line_idx, line) in s.lines().enumerate() {
-            let mut grid_line = Vec::new();
-            for cell_idx in 0..line.len() {
-                let cell = line[cell_idx..=cell_idx].parse()?;
-                if let DiagramCell::Source = cell {
-                    match source_coords {
-                        None => source_coords = Some((cell_idx, line_idx)),
-                        Some(_) => bail!("found two different sources in diagram"),
-                    }
-                }
-                grid_line.push(cell);
-            }
-            grid.push(grid_line);
+        let grid: Grid<DiagramCell> = s.parse()?;
+
+        let is_source = |cell: &DiagramCell| matches!(cell, DiagramCell::Source);
+        if grid.cells().iter().filter(|cell| is_source(cell)).count() > 1 {
+            bail!("found two different sources in diagram");
         }
 
-        source_coords
+        grid.position(is_source)
             .ok_or(anyhow!("couldn't find source in diagram"))
             .map(|source_coords| Self {
                 grid,
@@ -74,6 +74,13 @@ impl FromStr for ManifoldDiagram {
 }
 
 impl ManifoldDiagram {
+    /// Follows a ray going down from `source`, until it hits a splitter or leaves the diagram.
+    fn next_splitter(&self, source: Point) -> Option<Point> {
+        self.grid
+            .ray(source, (0, 1))
+            .find(|&p| !matches!(self.grid[p], DiagramCell::EmptySpace))
+    }
+
     fn count_ray_splits(&self) -> usize {
         let mut ray_sources = vec![self.source_coords];
         // TODO: we use a HashMap<(usize, usize), ()> because a HashSet doesn't have `entry` methods in stable:
@@ -82,22 +89,15 @@ impl ManifoldDiagram {
 
         let mut count = 0;
         while let Some(ray_source) = ray_sources.pop() {
-            let (x, mut y) = ray_source;
-            y += 1;
-            while y < self.grid.len()
-                && let DiagramCell::EmptySpace = self.grid[y][x]
-            {
-                y += 1;
-            }
-
-            if y == self.grid.len() {
+            let Some((x, y)) = self.next_splitter(ray_source) else {
                 // We've reached the end of the diagram, we can stop computing this ray
                 continue;
-            }
+            };
+
             // otherwise, we've hit a splitter (unless there are multiple sources, which shouldn't happen)
-            if let DiagramCell::Splitter = self.grid[y][x] {
+            if let DiagramCell::Splitter = self.grid[(x, y)] {
                 // make sure we don't exit the grid when splitting, shouldn't happen
-                assert!(x > 0 && x < (self.grid[0].len() - 1));
+                assert!(x > 0 && x < (self.grid.width() - 1));
 
                 if let Entry::Vacant(e) = used_splitters.entry((x, y)) {
                     e.insert(());
@@ -121,24 +121,16 @@ impl ManifoldDiagram {
         let mut timelines_from: HashMap<(usize, usize), usize> = HashMap::new();
 
         while let Some(ray_source) = ray_sources.pop() {
-            let (x, mut y) = ray_source;
-            y += 1;
-            while y < self.grid.len()
-                && let DiagramCell::EmptySpace = self.grid[y][x]
-            {
-                y += 1;
-            }
-
-            if y == self.grid.len() {
+            let Some((x, y)) = self.next_splitter(ray_source) else {
                 // We've reached the end of the diagram, this ray is a single timeline
                 timelines_from.insert(ray_source, 1);
                 continue;
-            }
+            };
 
             // Otherwise, we've hit a splitter (unless there are multiple sources, which shouldn't happen)
-            if let DiagramCell::Splitter = self.grid[y][x] {
+            if let DiagramCell::Splitter = self.grid[(x, y)] {
                 // Make sure we don't exit the grid when splitting, shouldn't happen
-                assert!(x > 0 && x < (self.grid[0].len() - 1));
+                assert!(x > 0 && x < (self.grid.width() - 1));
                 let (ray_left, ray_right) = ((x - 1, y), (x + 1, y));
 
                 let left = timelines_from.get(&ray_left);
@@ -168,14 +160,12 @@ impl ManifoldDiagram {
     }
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let diagram = input.parse::<ManifoldDiagram>()?;
-    Ok(diagram.count_ray_splits())
+fn part1(diagram: &ManifoldDiagram) -> usize {
+    diagram.count_ray_splits()
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let diagram = input.parse::<ManifoldDiagram>()?;
-    Ok(diagram.count_timelines())
+fn part2(diagram: &ManifoldDiagram) -> usize {
+    diagram.count_timelines()
 }
 
 #[cfg(test)]
@@ -186,21 +176,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 21);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 1537);
+        assert_eq!(part1(&PROVIDED.parse().unwrap()), 21);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 40);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 18818811755665);
+        assert_eq!(part2(&PROVIDED.parse().unwrap()), 40);
     }
 }
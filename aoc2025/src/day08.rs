@@ -1,17 +1,28 @@
 use anyhow::{Context, Result, bail};
-use std::{collections::HashMap, fmt::Write, hash::Hash, str::FromStr};
+use aoc::union_find::IndexUnionFind;
+use aoc::{Answer, Solution};
+use std::str::FromStr;
 
-const INPUT: &str = include_str!("../input/day08.txt");
+pub struct Day08;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
-    writeln!(res, "part 1: {}", part1(INPUT, 1000)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
-    Ok(res)
+impl Solution for Day08 {
+    type Input = Vec<JunctionBox>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(JunctionBox::from_str).collect()
+    }
+
+    fn part1(points: &Self::Input) -> Result<Answer> {
+        Ok(part1(points, 1000)?.into())
+    }
+
+    fn part2(points: &Self::Input) -> Result<Answer> {
+        Ok(part2(points)?.into())
+    }
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct JunctionBox {
+pub struct JunctionBox {
     x: usize,
     y: usize,
     z: usize,
@@ -45,161 +56,54 @@ impl JunctionBox {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct UnionFindKey(usize);
-
-/// UnionFind implementation that maintains a circular linked list in addition to the usual tree for
-/// each disjoint subset, allowing easy iteration on a subset's members.
-struct UnionFind<T: Sized + Hash> {
-    members_to_keys: HashMap<T, UnionFindKey>,
-    parent: Vec<UnionFindKey>,
-    #[allow(unused)]
-    next: Vec<UnionFindKey>,
-    size: Vec<usize>,
-}
-
-impl<T: Sized + Hash + Eq> UnionFind<T> {
-    fn from(members: Vec<T>) -> Self {
-        let keys: Vec<UnionFindKey> = (0..members.len()).map(UnionFindKey).collect();
-        Self {
-            parent: keys.clone(),
-            next: keys.clone(),
-            size: (0..members.len()).map(|_| 1).collect(),
-            members_to_keys: members.into_iter().zip(keys).collect(),
-        }
-    }
-
-    fn size(&self) -> usize {
-        self.parent.len()
-    }
-
-    fn get_key(&self, member: &T) -> Result<UnionFindKey> {
-        self.members_to_keys
-            .get(member)
-            .context("unknown member")
-            .copied()
-    }
-
-    fn union(&mut self, left: &T, right: &T) -> Result<UnionFindKey> {
-        let left = self.get_key(left)?;
-        let right = self.get_key(right)?;
-        Ok(self.union_by_key(left, right))
-    }
-
-    fn union_by_key(&mut self, left: UnionFindKey, right: UnionFindKey) -> UnionFindKey {
-        let mut left_root = self.find_by_key(left);
-        let mut right_root = self.find_by_key(right);
-
-        if left_root == right_root {
-            return left_root;
-        }
-
-        if self.size[left_root.0] < self.size[right_root.0] {
-            (left_root, right_root) = (right_root, left_root)
-        }
-
-        self.parent[right_root.0] = left_root;
-        let new_size = self.size[left_root.0] + self.size[right_root.0];
-        self.size[left_root.0] = new_size;
-
-        left_root
-    }
-
-    #[allow(unused)]
-    fn find(&mut self, member: &T) -> Result<UnionFindKey> {
-        let key = self.get_key(member)?;
-        Ok(self.find_by_key(key))
-    }
-
-    // This could be implemented with &self using interior mutability...
-    fn find_by_key(&mut self, key: UnionFindKey) -> UnionFindKey {
-        if key != self.parent[key.0] {
-            self.parent[key.0] = self.find_by_key(self.parent[key.0]);
+/// Every pair of boxes, as indices in `points`, closest pairs first.
+fn sorted_pairs(points: &[JunctionBox]) -> Vec<(usize, usize)> {
+    let mut distances = Vec::new();
+    for (i, pi) in points.iter().enumerate() {
+        for (j, pj) in points.iter().enumerate().skip(i + 1) {
+            distances.push((pi.distance_to(pj), (i, j)));
         }
-
-        self.parent[key.0]
-    }
-
-    fn roots(&self) -> impl Iterator<Item = UnionFindKey> {
-        self.parent
-            .iter()
-            .enumerate()
-            .filter_map(|(idx, &key)| if idx == key.0 { Some(key) } else { None })
     }
+    distances.sort_by(|(dist1, _), (dist2, _)| dist1.total_cmp(dist2));
 
-    fn get_size(&mut self, key: UnionFindKey) -> usize {
-        let root = self.find_by_key(key);
-        self.size[root.0]
-    }
+    distances.into_iter().map(|(_, pair)| pair).collect()
 }
 
-fn part1(input: &str, connections: usize) -> Result<usize> {
-    let points = input
-        .lines()
-        .map(JunctionBox::from_str)
-        .collect::<Result<Vec<_>>>()?;
-
-    let mut distances = Vec::new();
-    for (i, &pi) in points.iter().enumerate() {
-        for &pj in points.iter().skip(i + 1) {
-            distances.push((pi.distance_to(&pj), (pi, pj)));
-        }
-    }
-    distances.sort_by(|(dist1, _), (dist2, _)| dist1.total_cmp(dist2));
-    if connections > distances.len() {
+fn part1(points: &[JunctionBox], connections: usize) -> Result<usize> {
+    let pairs = sorted_pairs(points);
+    if connections > pairs.len() {
         bail!(
             "There are less possible matchings than the required number of connections: {} matchings",
-            distances.len()
+            pairs.len()
         );
     }
 
-    let mut uf = UnionFind::from(points);
-    for (_, (p1, p2)) in distances.into_iter().take(connections) {
-        uf.union(&p1, &p2)?;
+    let mut uf = IndexUnionFind::new(points.len());
+    for (i, j) in pairs.into_iter().take(connections) {
+        uf.union(i, j);
     }
 
-    let roots = uf.roots().collect::<Vec<_>>();
-    let mut sizes = roots.iter().map(|r| uf.get_size(*r)).collect::<Vec<_>>();
+    let mut sizes = uf.components().iter().map(Vec::len).collect::<Vec<_>>();
+    if sizes.len() < 3 {
+        bail!(
+            "less than 3 circuits left after {} connections",
+            connections
+        );
+    }
     sizes.sort_by_key(|v| std::cmp::Reverse(*v));
 
     Ok(sizes[0] * sizes[1] * sizes[2])
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let points = input
-        .lines()
-        .map(JunctionBox::from_str)
-        .collect::<Result<Vec<_>>>()?;
-
-    let mut distances = Vec::new();
-    for (i, &pi) in points.iter().enumerate() {
-        for &pj in points.iter().skip(i + 1) {
-            distances.push((pi.distance_to(&pj), (pi, pj)));
-        }
-    }
-    distances.sort_by(|(dist1, _), (dist2, _)| dist1.total_cmp(dist2));
-
-    let mut uf = UnionFind::from(points);
-    let mut unions = 0;
-    let mut last_pair = None;
-    for (_, (p1, p2)) in distances {
-        if unions >= (uf.size() - 1) {
-            break;
-        }
-        last_pair = Some((p1, p2));
-
-        if uf.find(&p1)? == uf.find(&p2)? {
-            continue;
+fn part2(points: &[JunctionBox]) -> Result<usize> {
+    let mut uf = IndexUnionFind::new(points.len());
+    for (i, j) in sorted_pairs(points) {
+        if uf.union(i, j) && uf.count() == 1 {
+            return Ok(points[i].x * points[j].x);
         }
-
-        uf.union(&p1, &p2)?;
-        unions += 1;
     }
 
-    Ok(match last_pair {
-        Some((p1, p2)) => p1.x * p2.x,
-        None => bail!("points was empty?"),
-    })
+    bail!("the boxes never form a single circuit")
 }
 
 #[cfg(test)]
@@ -210,21 +114,11 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED, 10).unwrap(), 40);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT, 1000).unwrap(), 127551);
+        assert_eq!(part1(&Day08::parse(PROVIDED).unwrap(), 10).unwrap(), 40);
     }
 
     #[test]
     fn part2_provided() {
-        assert_eq!(part2(PROVIDED).unwrap(), 25272);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 2347225200);
+        assert_eq!(part2(&Day08::parse(PROVIDED).unwrap()).unwrap(), 25272);
     }
 }
@@ -1,17 +1,27 @@
 use anyhow::{Context, Result, anyhow};
-use std::{fmt::Write, str::FromStr};
+use aoc::{Answer, Solution};
+use std::str::FromStr;
 
-const INPUT: &str = include_str!("../input/day09.txt");
+pub struct Day09;
 
-pub fn run() -> Result<String> {
-    let mut res = String::with_capacity(128);
-    writeln!(res, "part 1: {}", part1(INPUT)?)?;
-    writeln!(res, "part 2: {}", part2(INPUT)?)?;
-    Ok(res)
+impl Solution for Day09 {
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(points: &Self::Input) -> Result<Answer> {
+        Ok(part1(points)?.into())
+    }
+
+    fn part2(points: &Self::Input) -> Result<Answer> {
+        Ok(part2(points)?.into())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Point {
+pub struct Point {
     x: usize,
     y: usize,
 }
@@ -33,12 +43,7 @@ fn rectangle_area(p1: &Point, p2: &Point) -> usize {
     (p1.x.max(p2.x) - p1.x.min(p2.x) + 1) * (p1.y.max(p2.y) - p1.y.min(p2.y) + 1)
 }
 
-fn part1(input: &str) -> Result<usize> {
-    let points = input
-        .lines()
-        .map(|line| line.parse::<Point>())
-        .collect::<Result<Vec<_>>>()?;
-
+fn part1(points: &[Point]) -> Result<usize> {
     let mut max = None;
     for (idx, p1) in points.iter().enumerate() {
         for p2 in points.iter().skip(idx + 1) {
@@ -82,12 +87,7 @@ fn edge_intersects(&(e1, e2): &(Point, Point), (r1, r2): (&Point, &Point)) -> bo
     }
 }
 
-fn part2(input: &str) -> Result<usize> {
-    let points = input
-        .lines()
-        .map(|line| line.parse::<Point>())
-        .collect::<Result<Vec<_>>>()?;
-
+fn part2(points: &[Point]) -> Result<usize> {
     let mut edges = points.windows(2).map(|w| (w[0], w[1])).collect::<Vec<_>>();
     edges.push((
         *points.last().context("input was empty")?,
@@ -136,12 +136,7 @@ mod tests {
 
     #[test]
     fn part1_provided() {
-        assert_eq!(part1(PROVIDED).unwrap(), 50);
-    }
-
-    #[test]
-    fn part1_real() {
-        assert_eq!(part1(INPUT).unwrap(), 4758121828);
+        assert_eq!(part1(&Day09::parse(PROVIDED).unwrap()).unwrap(), 50);
     }
 
     #[test]
@@ -154,11 +149,6 @@ mod tests {
             &(Point { x: 9, y: 5 }, Point { x: 2, y: 5 }),
             (&Point { x: 9, y: 7 }, &Point { x: 2, y: 3 })
         ));
-        assert_eq!(part2(PROVIDED).unwrap(), 24);
-    }
-
-    #[test]
-    fn part2_real() {
-        assert_eq!(part2(INPUT).unwrap(), 1577956170);
+        assert_eq!(part2(&Day09::parse(PROVIDED).unwrap()).unwrap(), 24);
     }
 }
@@ -7,3 +7,5 @@ pub mod day06;
 pub mod day07;
 pub mod day08;
 pub mod day09;
+
+aoc::year!();